@@ -0,0 +1,297 @@
+use std::borrow::Cow;
+
+use claim_model::{
+    account_history::HistoryEntryKind,
+    account_record::{AccountPeriods, AccountRecord},
+    api::AccountApi,
+    error::ClaimError,
+    event::{emit, AccountData, AccountPeriodsData, EventKind},
+    AccountsPageView, Duration, PruneResultView, UnixTimestamp,
+};
+use near_sdk::{near_bindgen, require, AccountId};
+
+use crate::{
+    common::{is_lock_active, now_seconds, UnixTimestampExtension},
+    Contract, ContractExt, MAX_ACCOUNT_PERIOD,
+};
+
+#[near_bindgen]
+impl AccountApi for Contract {
+    fn disable_account(&mut self, account_id: AccountId) {
+        self.assert_oracle();
+
+        self.set_account_enabled(&account_id, false);
+        emit(EventKind::AccountDisabled(AccountData { account_id }));
+    }
+
+    fn enable_account(&mut self, account_id: AccountId) {
+        self.assert_oracle();
+
+        self.set_account_enabled(&account_id, true);
+        emit(EventKind::AccountEnabled(AccountData { account_id }));
+    }
+
+    fn get_accounts(&self, from_index: u32, limit: u32) -> AccountsPageView {
+        AccountsPageView {
+            account_ids: self.account_index.page(from_index, limit),
+            next_index: self.account_index.next_page(from_index, limit),
+        }
+    }
+
+    fn get_accounts_count(&self) -> u32 {
+        self.account_index.len()
+    }
+
+    fn index_accounts(&mut self, account_ids: Vec<AccountId>) -> u32 {
+        self.assert_oracle();
+
+        self.index_registered_accounts(account_ids)
+    }
+
+    fn set_account_periods(
+        &mut self,
+        account_id: AccountId,
+        claim_period: Option<Duration>,
+        burn_period: Option<Duration>,
+    ) {
+        self.assert_oracle();
+
+        require!(
+            [claim_period, burn_period]
+                .iter()
+                .flatten()
+                .all(|period| *period <= MAX_ACCOUNT_PERIOD),
+            ClaimError::InvalidConfig.to_string()
+        );
+
+        let now = now_seconds();
+
+        let account_data = self
+            .account(&account_id)
+            .unwrap_or_else(|| ClaimError::AccountNotFound.panic());
+        require!(
+            !is_lock_active(account_data.locked_at, now, self.lock_expiry),
+            ClaimError::AccountLocked.to_string()
+        );
+
+        // References expired under the previous burn period are logged and dropped before moving the rest.
+        self.prune_accruals(&account_id, now);
+
+        let previous_burn_period = self.periods(&account_id).burn_period;
+
+        if claim_period.is_none() && burn_period.is_none() {
+            self.account_periods.remove(&account_id);
+        } else {
+            self.account_periods.insert(
+                account_id.clone(),
+                AccountPeriods {
+                    claim_period,
+                    burn_period,
+                },
+            );
+        }
+
+        if previous_burn_period != burn_period {
+            self.move_accruals(&account_id, previous_burn_period, burn_period);
+            self.prune_accruals(&account_id, now);
+        }
+
+        emit(EventKind::AccountPeriodsChanged(AccountPeriodsData {
+            account_id,
+            claim_period,
+            burn_period,
+        }));
+    }
+
+    fn prune_accounts(&mut self, from_index: u32, limit: u32) -> PruneResultView {
+        self.assert_oracle();
+
+        let now = now_seconds();
+
+        let mut deleted = 0;
+        for account_id in self.account_index.page(from_index, limit) {
+            self.prune_accruals(&account_id, now);
+
+            if self.delete_if_idle(&account_id, now) {
+                deleted += 1;
+            }
+        }
+
+        PruneResultView {
+            deleted,
+            next_index: self.account_index.next_page(from_index, limit),
+        }
+    }
+}
+
+impl Contract {
+    /// Adds the accounts registered in `accounts` to the index and returns the number of added ones.
+    pub(crate) fn index_registered_accounts(&mut self, account_ids: Vec<AccountId>) -> u32 {
+        let mut indexed = 0;
+        for account_id in account_ids {
+            if self.upgrade_account(&account_id)
+                || (self.accounts.contains_key(&account_id) && self.account_index.insert(&account_id))
+            {
+                indexed += 1;
+            }
+        }
+
+        indexed
+    }
+
+    /// Returns the record of the account, converting a record of the previous version in place.
+    pub(crate) fn account(&self, account_id: &AccountId) -> Option<Cow<'_, AccountRecord>> {
+        if let Some(account_data) = self.accounts.get(account_id) {
+            return Some(Cow::Borrowed(account_data));
+        }
+
+        self.legacy_accounts
+            .get(account_id)
+            .map(|legacy| Cow::Owned(legacy.clone().upgrade(now_seconds())))
+    }
+
+    /// Returns the periods of the account overriding the contract-wide ones.
+    pub(crate) fn periods(&self, account_id: &AccountId) -> AccountPeriods {
+        self.account_periods.get(account_id).copied().unwrap_or_default()
+    }
+
+    /// Moves the record of the account stored by the previous version to `accounts` and adds
+    /// the account to the index, if there is such a record. Must be called before the record is modified.
+    /// Returns `true` if the account has been added to the index.
+    pub(crate) fn upgrade_account(&mut self, account_id: &AccountId) -> bool {
+        if self.accounts.contains_key(account_id) {
+            return false;
+        }
+
+        let Some(legacy) = self.legacy_accounts.remove(account_id) else {
+            return false;
+        };
+
+        self.accounts.insert(account_id.clone(), legacy.upgrade(now_seconds()));
+        self.account_index.insert(account_id)
+    }
+
+    fn set_account_enabled(&mut self, account_id: &AccountId, is_enabled: bool) {
+        self.upgrade_account(account_id);
+
+        let account_data = self
+            .accounts
+            .get_mut(account_id)
+            .unwrap_or_else(|| ClaimError::AccountNotFound.panic());
+        account_data.is_enabled = is_enabled;
+    }
+
+    /// Removes references to expired accrual entries from the account, including burnt ones.
+    /// Tokens of expired entries stay in their buckets until `burn`, they are logged
+    /// to the account's history as expired.
+    ///
+    /// References are checked by their timestamps, so live entries aren't read. An expired entry
+    /// is read once when its reference is removed, and only if the history is enabled.
+    pub(crate) fn prune_accruals(&mut self, account_id: &AccountId, now: UnixTimestamp) {
+        self.upgrade_account(account_id);
+
+        let burn_override = self.periods(account_id).burn_period;
+        let burn_period = burn_override.unwrap_or(self.burn_period);
+
+        let Some(account_data) = self.accounts.get_mut(account_id) else {
+            return;
+        };
+
+        let is_history_enabled = self.history_depth > 0;
+
+        let mut total_expired = 0;
+        account_data.accruals.retain(|(datetime, index)| {
+            if datetime.is_within_period(now, burn_period) {
+                return true;
+            }
+
+            if is_history_enabled {
+                total_expired += self
+                    .accruals
+                    .get((burn_override, *datetime))
+                    .and_then(|(accruals, _)| accruals.get(*index))
+                    .copied()
+                    .unwrap_or_default();
+            }
+
+            false
+        });
+
+        if total_expired > 0 {
+            self.push_history(account_id, HistoryEntryKind::Expire, total_expired, now);
+        }
+    }
+
+    /// Deletes the account if it has no accruals and hasn't claimed for longer than `burn_period`.
+    /// Disabled and locked accounts are kept, as well as accounts with their own periods,
+    /// which would otherwise fall back to the contract-wide ones on the next record.
+    pub(crate) fn delete_if_idle(&mut self, account_id: &AccountId, now: UnixTimestamp) -> bool {
+        self.upgrade_account(account_id);
+
+        let Some(account_data) = self.accounts.get(account_id) else {
+            return false;
+        };
+        let burn_period = self.periods(account_id).burn_period_or(self.burn_period);
+
+        let is_idle = account_data.accruals.is_empty()
+            && account_data.is_enabled
+            && !is_lock_active(account_data.locked_at, now, self.lock_expiry)
+            && now.saturating_sub(account_data.claim_period_refreshed_at) > burn_period
+            && !self.account_periods.contains_key(account_id);
+
+        if is_idle {
+            self.remove_account(account_id);
+        }
+
+        is_idle
+    }
+
+    /// Deletes all data of the account, including its history.
+    pub(crate) fn remove_account(&mut self, account_id: &AccountId) {
+        self.accounts.remove(account_id);
+        self.history.remove(account_id);
+        self.legacy_accounts.remove(account_id);
+        self.account_periods.remove(account_id);
+        self.account_index.remove(account_id);
+        self.failed_claims.remove(account_id);
+    }
+
+    /// Moves the account's accruals from the buckets of one burn period override to the buckets of another,
+    /// keeping their timestamps. The account's references are updated to the new entries.
+    fn move_accruals(&mut self, account_id: &AccountId, from: Option<Duration>, to: Option<Duration>) {
+        let Some(account_data) = self.accounts.get_mut(account_id) else {
+            return;
+        };
+
+        let mut moved = Vec::with_capacity(account_data.accruals.len());
+        for (datetime, index) in account_data.accruals.drain(..) {
+            let Some((accruals, total)) = self.accruals.get_mut((from, datetime)) else {
+                continue;
+            };
+
+            let Some(amount) = accruals.get_mut(index).filter(|amount| **amount > 0) else {
+                continue;
+            };
+
+            moved.push((datetime, *amount));
+            *total -= *amount;
+            *amount = 0;
+        }
+
+        for (datetime, amount) in moved {
+            let key = (to, datetime);
+            let ((accruals, total), is_created) = self.accruals.get_or_create(key);
+            if is_created {
+                self.bucket_index.insert(&key);
+            }
+
+            accruals.push(amount);
+            *total += amount;
+            account_data.accruals.push((datetime, accruals.len() - 1));
+        }
+
+        if let Some(oldest) = account_data.accruals.iter().map(|(datetime, _)| *datetime).min() {
+            self.stats.oldest_bucket_at = Some(self.stats.oldest_bucket_at.map_or(oldest, |at| at.min(oldest)));
+        }
+    }
+}
@@ -0,0 +1,426 @@
+#![cfg(test)]
+
+use claim_model::{
+    account_history::HistoryEntryKind,
+    api::{AccountApi, BurnApi, ClaimApi, CleanApi, ConfigApi, HistoryApi, InvariantApi, RecordApi, StatsApi},
+    AccountsPageView, ClaimAvailabilityView,
+};
+use near_sdk::{json_types::U128, test_utils::get_logs, PromiseOrValue};
+
+use crate::{
+    burn::api::test::EXT_BURN_FUTURE,
+    common::tests::{data::set_test_future_success, Context},
+    MAX_ACCOUNT_PERIOD,
+};
+
+#[test]
+fn disable_account_by_oracle() {
+    let (mut context, mut contract, accounts) = Context::init_with_oracle();
+
+    context.switch_account(&accounts.oracle);
+    contract.record_batch_for_hold(vec![(accounts.alice.clone(), U128(100_000))]);
+    contract.disable_account(accounts.alice.clone());
+
+    context.set_block_timestamp_in_seconds(contract.claim_period as u64 + 100);
+
+    let alice_can_claim = contract.is_claim_available(accounts.alice.clone());
+    assert_eq!(ClaimAvailabilityView::Disabled, alice_can_claim);
+
+    contract.record_batch_for_hold(vec![(accounts.alice.clone(), U128(200_000))]);
+
+    let alice_balance = contract.get_claimable_balance_for_account(accounts.alice.clone()).0;
+    assert_eq!(300_000, alice_balance);
+}
+
+#[test]
+fn enable_account_by_oracle() {
+    let (mut context, mut contract, accounts) = Context::init_with_oracle();
+
+    context.switch_account(&accounts.oracle);
+    contract.record_batch_for_hold(vec![(accounts.alice.clone(), U128(100_000))]);
+    contract.disable_account(accounts.alice.clone());
+    contract.enable_account(accounts.alice.clone());
+
+    context.set_block_timestamp_in_seconds(contract.claim_period as u64 + 100);
+
+    let alice_can_claim = contract.is_claim_available(accounts.alice.clone());
+    assert_eq!(ClaimAvailabilityView::Available, alice_can_claim);
+}
+
+#[test]
+#[should_panic(expected = "Claim is not available at the moment")]
+fn claim_by_disabled_account() {
+    let (mut context, mut contract, accounts) = Context::init_with_oracle();
+
+    context.switch_account(&accounts.oracle);
+    contract.record_batch_for_hold(vec![(accounts.alice.clone(), U128(100_000))]);
+    contract.disable_account(accounts.alice.clone());
+
+    context.set_block_timestamp_in_seconds(contract.claim_period as u64 + 100);
+
+    context.switch_account(&accounts.alice);
+    contract.claim();
+}
+
+#[test]
+#[should_panic(expected = "Unauthorized access")]
+fn disable_account_by_not_oracle() {
+    let (mut context, mut contract, accounts) = Context::init_with_oracle();
+
+    context.switch_account(&accounts.oracle);
+    contract.record_batch_for_hold(vec![(accounts.alice.clone(), U128(100_000))]);
+
+    context.switch_account(&accounts.alice);
+    contract.disable_account(accounts.alice.clone());
+}
+
+#[test]
+#[should_panic(expected = "Account data is not found")]
+fn disable_not_registered_account() {
+    let (mut context, mut contract, accounts) = Context::init_with_oracle();
+
+    context.switch_account(&accounts.oracle);
+    contract.disable_account(accounts.alice.clone());
+}
+
+#[test]
+fn get_registered_accounts() {
+    let (mut context, mut contract, accounts) = Context::init_with_oracle();
+
+    assert_eq!(0, contract.get_accounts_count());
+    assert!(contract.get_accounts(0, 10).account_ids.is_empty());
+
+    context.switch_account(&accounts.oracle);
+    contract.record_batch_for_hold(vec![
+        (accounts.alice.clone(), U128(100_000)),
+        (accounts.bob.clone(), U128(200_000)),
+        (accounts.alice.clone(), U128(300_000)),
+    ]);
+
+    assert_eq!(2, contract.get_accounts_count());
+    assert_eq!(
+        AccountsPageView {
+            account_ids: vec![accounts.alice.clone(), accounts.bob.clone()],
+            next_index: None,
+        },
+        contract.get_accounts(0, 10)
+    );
+    assert_eq!(
+        AccountsPageView {
+            account_ids: vec![accounts.alice.clone()],
+            next_index: Some(1),
+        },
+        contract.get_accounts(0, 1)
+    );
+
+    contract.clean(vec![accounts.alice.clone()]);
+
+    assert_eq!(1, contract.get_accounts_count());
+    assert_eq!(
+        AccountsPageView {
+            account_ids: vec![],
+            next_index: Some(1),
+        },
+        contract.get_accounts(0, 1)
+    );
+    assert_eq!(vec![accounts.bob.clone()], contract.get_accounts(1, 1).account_ids);
+
+    contract.record_batch_for_hold(vec![(accounts.alice.clone(), U128(400_000))]);
+
+    assert_eq!(vec![accounts.bob.clone()], contract.get_accounts(1, 1).account_ids);
+    assert_eq!(
+        vec![accounts.bob.clone(), accounts.alice.clone()],
+        contract.get_accounts(0, 10).account_ids
+    );
+}
+
+#[test]
+fn index_registered_accounts() {
+    let (mut context, mut contract, accounts) = Context::init_with_oracle();
+
+    context.switch_account(&accounts.oracle);
+    contract.record_batch_for_hold(vec![(accounts.alice.clone(), U128(100_000))]);
+    contract.account_index.remove(&accounts.alice);
+
+    let indexed = contract.index_accounts(vec![accounts.alice.clone(), accounts.bob.clone()]);
+    assert_eq!(1, indexed);
+    assert_eq!(vec![accounts.alice.clone()], contract.get_accounts(0, 10).account_ids);
+
+    let indexed = contract.index_accounts(vec![accounts.alice.clone()]);
+    assert_eq!(0, indexed);
+}
+
+#[test]
+#[should_panic(expected = "Unauthorized access")]
+fn index_accounts_by_not_oracle() {
+    let (mut context, mut contract, accounts) = Context::init_with_oracle();
+
+    context.switch_account(&accounts.alice);
+    contract.index_accounts(vec![accounts.alice.clone()]);
+}
+
+#[test]
+fn record_prunes_burnt_accruals() {
+    let (mut context, mut contract, accounts) = Context::init_with_oracle();
+    set_test_future_success(EXT_BURN_FUTURE, true);
+
+    context.switch_account(&accounts.oracle);
+    contract.record_batch_for_hold(vec![(accounts.alice.clone(), U128(100_000))]);
+
+    context.set_block_timestamp_in_seconds(contract.burn_period as u64 + 100);
+    contract.burn();
+    contract.record_batch_for_hold(vec![(accounts.alice.clone(), U128(200_000))]);
+
+    let alice_record = contract.accounts.get(&accounts.alice).unwrap();
+    assert_eq!(1, alice_record.accruals.len());
+    assert_eq!(200_000, contract.get_claimable_balance_for_account(accounts.alice).0);
+}
+
+#[test]
+fn prune_accounts_deletes_idle_accounts() {
+    let (mut context, mut contract, accounts) = Context::init_with_oracle();
+
+    context.switch_account(&accounts.oracle);
+    contract.set_history_depth(10);
+    contract.record_batch_for_hold(vec![
+        (accounts.alice.clone(), U128(100_000)),
+        (accounts.bob.clone(), U128(200_000)),
+    ]);
+    contract.disable_account(accounts.bob.clone());
+
+    context.set_block_timestamp_in_seconds(contract.burn_period as u64 + 100);
+
+    let result = contract.prune_accounts(0, 10);
+    assert_eq!(1, result.deleted);
+    assert_eq!(None, result.next_index);
+
+    assert!(contract.accounts.get(&accounts.alice).is_none());
+    assert_eq!(vec![accounts.bob.clone()], contract.get_accounts(0, 10).account_ids);
+
+    let bob_record = contract.accounts.get(&accounts.bob).unwrap();
+    assert!(bob_record.accruals.is_empty());
+
+    assert!(contract.get_account_history(accounts.alice, 0, 10).is_empty());
+
+    let bob_history = contract.get_account_history(accounts.bob, 0, 10);
+    assert_eq!(HistoryEntryKind::Expire, bob_history.last().unwrap().kind);
+    assert_eq!(200_000, bob_history.last().unwrap().amount.0);
+}
+
+#[test]
+fn prune_accounts_in_pages() {
+    let (mut context, mut contract, accounts) = Context::init_with_oracle();
+
+    context.switch_account(&accounts.oracle);
+    contract.record_batch_for_hold(vec![
+        (accounts.alice.clone(), U128(100_000)),
+        (accounts.bob.clone(), U128(200_000)),
+    ]);
+
+    context.set_block_timestamp_in_seconds(contract.burn_period as u64 + 100);
+
+    let result = contract.prune_accounts(0, 1);
+    assert_eq!(1, result.deleted);
+    assert_eq!(Some(1), result.next_index);
+
+    let result = contract.prune_accounts(1, 1);
+    assert_eq!(1, result.deleted);
+    assert_eq!(None, result.next_index);
+
+    assert_eq!(0, contract.get_accounts_count());
+}
+
+#[test]
+fn prune_accounts_keeps_accounts_with_live_accruals() {
+    let (mut context, mut contract, accounts) = Context::init_with_oracle();
+
+    context.switch_account(&accounts.oracle);
+    contract.record_batch_for_hold(vec![(accounts.alice.clone(), U128(100_000))]);
+
+    context.set_block_timestamp_in_seconds(contract.burn_period as u64 + 100);
+    contract.record_batch_for_hold(vec![(accounts.alice.clone(), U128(200_000))]);
+
+    let result = contract.prune_accounts(0, 10);
+    assert_eq!(0, result.deleted);
+    assert_eq!(200_000, contract.get_claimable_balance_for_account(accounts.alice).0);
+}
+
+#[test]
+fn prune_accounts_keeps_accounts_with_own_periods() {
+    let (mut context, mut contract, accounts) = Context::init_with_oracle();
+
+    context.switch_account(&accounts.oracle);
+    contract.record_batch_for_hold(vec![(accounts.alice.clone(), U128(100_000))]);
+    contract.set_account_periods(accounts.alice.clone(), None, Some(1_000));
+
+    context.set_block_timestamp_in_seconds(contract.burn_period as u64 + 100);
+
+    let result = contract.prune_accounts(0, 10);
+    assert_eq!(0, result.deleted);
+    assert!(contract.accounts.get(&accounts.alice).unwrap().accruals.is_empty());
+    assert_eq!(
+        Some(1_000),
+        contract.account_periods.get(&accounts.alice).unwrap().burn_period
+    );
+}
+
+#[test]
+#[should_panic(expected = "Unauthorized access")]
+fn prune_accounts_by_not_oracle() {
+    let (mut context, mut contract, accounts) = Context::init_with_oracle();
+
+    context.switch_account(&accounts.alice);
+    contract.prune_accounts(0, 10);
+}
+
+#[test]
+fn account_with_shorter_claim_period() {
+    let (mut context, mut contract, accounts) = Context::init_with_oracle();
+
+    context.switch_account(&accounts.oracle);
+    contract.record_batch_for_hold(vec![
+        (accounts.alice.clone(), U128(100_000)),
+        (accounts.bob.clone(), U128(200_000)),
+    ]);
+    contract.set_account_periods(accounts.alice.clone(), Some(10), None);
+
+    assert!(get_logs()
+        .last()
+        .unwrap()
+        .contains(r#""data":{"account_id":"alice","claim_period":10,"burn_period":null}"#));
+
+    context.set_block_timestamp_in_seconds(20);
+
+    assert_eq!(
+        ClaimAvailabilityView::Available,
+        contract.is_claim_available(accounts.alice.clone())
+    );
+    assert_eq!(
+        ClaimAvailabilityView::Unavailable((0, contract.claim_period)),
+        contract.is_claim_available(accounts.bob.clone())
+    );
+    assert_eq!(11, contract.get_account(accounts.alice).unwrap().next_claim_at);
+}
+
+#[test]
+fn account_with_longer_burn_period() {
+    let (mut context, mut contract, accounts) = Context::init_with_oracle();
+    set_test_future_success(EXT_BURN_FUTURE, true);
+
+    context.switch_account(&accounts.oracle);
+    contract.record_batch_for_hold(vec![
+        (accounts.alice.clone(), U128(100_000)),
+        (accounts.bob.clone(), U128(200_000)),
+    ]);
+
+    let alice_burn_period = contract.burn_period * 2;
+    contract.set_account_periods(accounts.alice.clone(), None, Some(alice_burn_period));
+
+    context.set_block_timestamp_in_seconds(contract.burn_period as u64 + 100);
+
+    let PromiseOrValue::Value(burnt_amount) = contract.burn() else {
+        panic!("Expected value");
+    };
+    assert_eq!(200_000, burnt_amount.0);
+    assert_eq!(
+        100_000,
+        contract.get_claimable_balance_for_account(accounts.alice.clone()).0
+    );
+    assert_eq!(0, contract.get_claimable_balance_for_account(accounts.bob.clone()).0);
+    assert_eq!(
+        vec![(alice_burn_period, U128(100_000))],
+        contract.get_expiry_schedule(accounts.alice.clone())
+    );
+
+    context.set_block_timestamp_in_seconds(alice_burn_period as u64 + 100);
+
+    let PromiseOrValue::Value(burnt_amount) = contract.burn() else {
+        panic!("Expected value");
+    };
+    assert_eq!(100_000, burnt_amount.0);
+    assert_eq!(0, contract.get_claimable_balance_for_account(accounts.alice).0);
+    assert_eq!(0, contract.stats.total_held);
+}
+
+#[test]
+fn set_account_periods_moves_accruals_between_buckets() {
+    let (mut context, mut contract, accounts) = Context::init_with_oracle();
+
+    context.switch_account(&accounts.oracle);
+    contract.record_batch_for_hold(vec![
+        (accounts.alice.clone(), U128(100_000)),
+        (accounts.bob.clone(), U128(200_000)),
+    ]);
+
+    let alice_burn_period = contract.burn_period * 2;
+    contract.set_account_periods(accounts.alice.clone(), None, Some(alice_burn_period));
+
+    assert_eq!(200_000, contract.accruals.get((None, 0)).unwrap().1);
+    assert_eq!(100_000, contract.accruals.get((Some(alice_burn_period), 0)).unwrap().1);
+    assert_eq!(2, contract.get_stats().buckets_count);
+
+    context.set_block_timestamp_in_seconds(10);
+    contract.record_batch_for_hold(vec![(accounts.alice.clone(), U128(50_000))]);
+    assert_eq!(50_000, contract.accruals.get((Some(alice_burn_period), 10)).unwrap().1);
+    assert!(contract.accruals.get((None, 10)).is_none());
+
+    contract.set_account_periods(accounts.alice.clone(), None, None);
+
+    assert_eq!(300_000, contract.accruals.get((None, 0)).unwrap().1);
+    assert_eq!(50_000, contract.accruals.get((None, 10)).unwrap().1);
+    assert_eq!(0, contract.accruals.get((Some(alice_burn_period), 0)).unwrap().1);
+    assert_eq!(
+        150_000,
+        contract.get_claimable_balance_for_account(accounts.alice.clone()).0
+    );
+    assert!(contract.check_invariants(None, 10).is_ok());
+    assert!(contract.account_periods.get(&accounts.alice).is_none());
+}
+
+#[test]
+fn account_periods_are_removed_with_account() {
+    let (mut context, mut contract, accounts) = Context::init_with_oracle();
+
+    context.switch_account(&accounts.oracle);
+    contract.record_batch_for_hold(vec![(accounts.alice.clone(), U128(100_000))]);
+    contract.set_account_periods(accounts.alice.clone(), Some(10), None);
+
+    contract.clean(vec![accounts.alice.clone()]);
+
+    assert!(contract.account_periods.get(&accounts.alice).is_none());
+}
+
+#[test]
+#[should_panic(expected = "Another operation is running")]
+fn set_account_periods_of_locked_account() {
+    let (mut context, mut contract, accounts) = Context::init_with_oracle();
+
+    context.switch_account(&accounts.oracle);
+    contract.record_batch_for_hold(vec![(accounts.alice.clone(), U128(100_000))]);
+    contract.accounts.get_mut(&accounts.alice).unwrap().locked_at = Some(0);
+
+    contract.set_account_periods(accounts.alice.clone(), None, Some(contract.burn_period * 2));
+}
+
+#[test]
+#[should_panic(expected = "Unauthorized access")]
+fn set_account_periods_by_not_oracle() {
+    let (mut context, mut contract, accounts) = Context::init_with_oracle();
+
+    context.switch_account(&accounts.oracle);
+    contract.record_batch_for_hold(vec![(accounts.alice.clone(), U128(100_000))]);
+
+    context.switch_account(&accounts.alice);
+    contract.set_account_periods(accounts.alice.clone(), Some(10), None);
+}
+
+#[test]
+#[should_panic(expected = "Configuration value is out of range")]
+fn set_too_long_account_periods() {
+    let (mut context, mut contract, accounts) = Context::init_with_oracle();
+
+    context.switch_account(&accounts.oracle);
+    contract.record_batch_for_hold(vec![(accounts.alice.clone(), U128(100_000))]);
+
+    contract.set_account_periods(accounts.alice.clone(), None, Some(MAX_ACCOUNT_PERIOD + 1));
+}
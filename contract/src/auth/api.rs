@@ -1,5 +1,9 @@
-use claim_model::api::AuthApi;
-use near_sdk::{env::log_str, near_bindgen, require, AccountId};
+use claim_model::{
+    api::AuthApi,
+    error::ClaimError,
+    event::{emit, EventKind, OracleData},
+};
+use near_sdk::{near_bindgen, require, AccountId};
 
 use crate::{Contract, ContractExt};
 
@@ -8,15 +12,18 @@ impl AuthApi for Contract {
     fn add_oracle(&mut self, account_id: AccountId) {
         Self::assert_private();
 
-        require!(self.oracles.insert(account_id.clone()), "Already exists");
-        log_str(&format!("Oracle {account_id} was added"));
+        require!(
+            self.oracles.insert(account_id.clone()),
+            ClaimError::OracleAlreadyExists.to_string()
+        );
+        emit(EventKind::OracleAdded(OracleData { account_id }));
     }
 
     fn remove_oracle(&mut self, account_id: AccountId) {
         Self::assert_private();
 
-        require!(self.oracles.remove(&account_id), "No such oracle");
-        log_str(&format!("Oracle {account_id} was removed"));
+        require!(self.oracles.remove(&account_id), ClaimError::NoSuchOracle.to_string());
+        emit(EventKind::OracleRemoved(OracleData { account_id }));
     }
 
     fn get_oracles(&self) -> Vec<AccountId> {
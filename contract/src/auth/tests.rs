@@ -1,6 +1,7 @@
 #![cfg(test)]
 
 use claim_model::api::AuthApi;
+use near_sdk::test_utils::get_logs;
 
 use crate::common::tests::Context;
 
@@ -14,6 +15,21 @@ fn add_oracle_by_contract_owner() {
     assert_eq!(oracles, vec![accounts.oracle.clone()]);
 }
 
+#[test]
+fn add_and_remove_oracle_emits_events() {
+    let (mut context, mut contract, accounts) = Context::init();
+
+    context.switch_account(&accounts.owner);
+    contract.add_oracle(accounts.oracle.clone());
+    contract.remove_oracle(accounts.oracle.clone());
+
+    let logs = get_logs();
+    assert_eq!(2, logs.len());
+    assert!(logs[0].contains(r#""event":"oracle_added""#));
+    assert!(logs[1].contains(r#""event":"oracle_removed""#));
+    assert!(logs.iter().all(|log| log.contains(r#""account_id":"oracle""#)));
+}
+
 #[test]
 #[should_panic(expected = "Method is private")]
 fn add_oracle_not_by_contract_owner() {
@@ -24,7 +40,7 @@ fn add_oracle_not_by_contract_owner() {
 }
 
 #[test]
-#[should_panic(expected = "Already exists")]
+#[should_panic(expected = "Oracle already exists")]
 fn add_oracle_twice() {
     let (mut context, mut contract, accounts) = Context::init();
 
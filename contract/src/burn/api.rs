@@ -1,12 +1,13 @@
 use claim_model::{
     api::BurnApi,
+    error::ClaimError,
     event::{emit, BurnData, EventKind},
     TokensAmount, UnixTimestamp,
 };
 use near_sdk::{json_types::U128, near_bindgen, require, PromiseOrValue};
 
 use crate::{
-    common::{now_seconds, UnixTimestampExtension},
+    common::{buckets::BucketKey, now_seconds, UnixTimestampExtension},
     Contract, ContractExt,
 };
 
@@ -14,26 +15,37 @@ use crate::{
 impl BurnApi for Contract {
     fn burn(&mut self) -> PromiseOrValue<U128> {
         self.assert_oracle();
+        Self::assert_enough_gas(self.gas_config.required_for_burn());
 
-        require!(!self.is_service_call_running, "Another service call is running");
+        let now = now_seconds();
 
-        self.is_service_call_running = true;
+        // Unlike account locks, the service call lock never expires on its own: a burn whose callback
+        // is still pending would otherwise be repeated for the same buckets.
+        require!(
+            self.service_call_started_at.is_none(),
+            ClaimError::ServiceCallRunning.to_string()
+        );
 
-        let mut total_to_burn = 0;
-        let mut keys_to_remove = vec![];
-        let now = now_seconds();
+        self.service_call_started_at = Some(now);
 
-        for (datetime, (_, total)) in self.accruals.iter() {
-            if !datetime.is_within_period(now, self.burn_period) {
-                keys_to_remove.push(*datetime);
+        let mut total_to_burn = self.pending_burn;
+        let mut keys_to_remove = vec![];
+        let mut oldest_kept: Option<UnixTimestamp> = None;
+
+        // Accounts with a burn period override have buckets of their own, which expire on that period.
+        for ((burn_override, datetime), (_, total)) in self.accruals.iter() {
+            if datetime.is_within_period(now, burn_override.unwrap_or(self.burn_period)) {
+                oldest_kept = Some(oldest_kept.map_or(datetime, |oldest| oldest.min(datetime)));
+            } else {
+                keys_to_remove.push((burn_override, datetime));
                 total_to_burn += total;
             }
         }
 
         if total_to_burn > 0 {
-            self.burn_external(total_to_burn, keys_to_remove)
+            self.burn_external(total_to_burn, keys_to_remove, oldest_kept)
         } else {
-            self.is_service_call_running = false;
+            self.service_call_started_at = None;
 
             PromiseOrValue::Value(U128(0))
         }
@@ -41,22 +53,41 @@ impl BurnApi for Contract {
 }
 
 impl Contract {
-    fn on_burn_internal(
+    pub(crate) fn on_burn_internal(
         &mut self,
         total_to_burn: TokensAmount,
-        keys_to_remove: Vec<UnixTimestamp>,
+        keys_to_remove: Vec<BucketKey>,
+        oldest_kept: Option<UnixTimestamp>,
         is_success: bool,
     ) -> U128 {
-        self.is_service_call_running = false;
+        self.service_call_started_at = None;
 
         if !is_success {
             return U128(0);
         }
 
-        for datetime in keys_to_remove {
-            self.accruals.remove(&datetime);
+        let mut total_removed = self.pending_burn;
+        for key in keys_to_remove {
+            if let Some((_, total)) = self.accruals.remove(key) {
+                total_removed += total;
+            }
+            self.bucket_index.remove(&key);
         }
 
+        // Tokens returned into the removed entries by failed claims while the burn was in flight
+        // weren't burnt, they are left for the next burn.
+        self.pending_burn = total_removed.saturating_sub(total_to_burn);
+
+        self.stats.release_held(total_to_burn);
+        self.stats.total_burned += total_to_burn;
+
+        if let Some(newest) = self.stats.newest_bucket_at {
+            if !self.accruals.contains_timestamp(newest) {
+                self.stats.newest_bucket_at = None;
+            }
+        }
+        self.stats.oldest_bucket_at = oldest_kept.or(self.stats.newest_bucket_at);
+
         emit(EventKind::Burn(BurnData {
             burnt_amount: U128(total_to_burn),
         }));
@@ -69,22 +100,32 @@ impl Contract {
 pub(crate) mod prod {
     use claim_model::{TokensAmount, UnixTimestamp};
     use near_sdk::{
-        env, ext_contract, is_promise_success, json_types::U128, near_bindgen, serde_json::json, Gas, Promise,
+        env, ext_contract, is_promise_success, json_types::U128, near_bindgen, serde_json::json, GasWeight, Promise,
         PromiseOrValue,
     };
 
-    use crate::{Contract, ContractExt};
+    use crate::{common::buckets::BucketKey, Contract, ContractExt};
 
     #[ext_contract(ext_self)]
     pub trait SelfCallback {
-        fn on_burn(&mut self, total_to_burn: TokensAmount, keys_to_remove: Vec<UnixTimestamp>) -> U128;
+        fn on_burn(
+            &mut self,
+            total_to_burn: TokensAmount,
+            keys_to_remove: Vec<BucketKey>,
+            oldest_kept: Option<UnixTimestamp>,
+        ) -> U128;
     }
 
     #[near_bindgen]
     impl SelfCallback for Contract {
         #[private]
-        fn on_burn(&mut self, total_to_burn: TokensAmount, keys_to_remove: Vec<UnixTimestamp>) -> U128 {
-            self.on_burn_internal(total_to_burn, keys_to_remove, is_promise_success())
+        fn on_burn(
+            &mut self,
+            total_to_burn: TokensAmount,
+            keys_to_remove: Vec<BucketKey>,
+            oldest_kept: Option<UnixTimestamp>,
+        ) -> U128 {
+            self.on_burn_internal(total_to_burn, keys_to_remove, oldest_kept, is_promise_success())
         }
     }
 
@@ -92,7 +133,8 @@ pub(crate) mod prod {
         pub(crate) fn burn_external(
             &mut self,
             total_to_burn: TokensAmount,
-            keys_to_remove: Vec<UnixTimestamp>,
+            keys_to_remove: Vec<BucketKey>,
+            oldest_kept: Option<UnixTimestamp>,
         ) -> PromiseOrValue<U128> {
             let args = json!({
                 "amount": U128(total_to_burn),
@@ -101,12 +143,15 @@ pub(crate) mod prod {
             .as_bytes()
             .to_vec();
 
+            // The callback gets its minimum gas plus everything left of the prepaid gas
+            // once this call finishes, while the burn gets exactly the configured amount.
             Promise::new(self.token_account_id.clone())
-                .function_call("burn".to_string(), args, 0, Gas(5 * Gas::ONE_TERA.0))
+                .function_call_weight("burn".to_string(), args, 0, self.gas_config.burn, GasWeight(0))
                 .then(
                     ext_self::ext(env::current_account_id())
-                        .with_static_gas(Gas(5 * Gas::ONE_TERA.0))
-                        .on_burn(total_to_burn, keys_to_remove),
+                        .with_static_gas(self.gas_config.burn_callback)
+                        .with_unused_gas_weight(1)
+                        .on_burn(total_to_burn, keys_to_remove, oldest_kept),
                 )
                 .into()
         }
@@ -118,7 +163,10 @@ pub(crate) mod test {
     use claim_model::{TokensAmount, UnixTimestamp};
     use near_sdk::{json_types::U128, PromiseOrValue};
 
-    use crate::{common::tests::data::get_test_future_success, Contract};
+    use crate::{
+        common::{buckets::BucketKey, tests::data::get_test_future_success},
+        Contract,
+    };
 
     pub(crate) const EXT_BURN_FUTURE: &str = "ext_burn";
 
@@ -126,11 +174,13 @@ pub(crate) mod test {
         pub(crate) fn burn_external(
             &mut self,
             total_to_burn: TokensAmount,
-            keys_to_remove: Vec<UnixTimestamp>,
+            keys_to_remove: Vec<BucketKey>,
+            oldest_kept: Option<UnixTimestamp>,
         ) -> PromiseOrValue<U128> {
             PromiseOrValue::Value(self.on_burn_internal(
                 total_to_burn,
                 keys_to_remove,
+                oldest_kept,
                 get_test_future_success(EXT_BURN_FUTURE),
             ))
         }
@@ -1,7 +1,7 @@
 #![cfg(test)]
 
 use claim_model::api::{BurnApi, ClaimApi, RecordApi};
-use near_sdk::{json_types::U128, PromiseOrValue};
+use near_sdk::{json_types::U128, Gas, PromiseOrValue};
 
 use crate::{
     burn::api::test::EXT_BURN_FUTURE,
@@ -38,7 +38,7 @@ fn test_burn_when_outdated_tokens_exist() {
     let bob_new_balance = contract.get_claimable_balance_for_account(accounts.bob).0;
     assert_eq!(0, bob_new_balance);
 
-    assert!(!contract.is_service_call_running);
+    assert!(contract.service_call_started_at.is_none());
 }
 
 #[test]
@@ -71,7 +71,7 @@ fn test_ext_error_on_burn_when_outdated_tokens_exist() {
     let bob_new_balance = contract.get_claimable_balance_for_account(accounts.bob).0;
     assert_eq!(0, bob_new_balance);
 
-    assert!(!contract.is_service_call_running);
+    assert!(contract.service_call_started_at.is_none());
 }
 
 #[test]
@@ -102,5 +102,68 @@ fn test_burn_when_outdated_tokens_don_not_exist() {
     let bob_new_balance = contract.get_claimable_balance_for_account(accounts.bob).0;
     assert_eq!(bob_balance, bob_new_balance);
 
-    assert!(!contract.is_service_call_running);
+    assert!(contract.service_call_started_at.is_none());
+}
+
+#[test]
+#[should_panic(expected = "Not enough gas attached")]
+fn test_burn_with_not_enough_gas() {
+    let (mut context, mut contract, accounts) = Context::init_with_oracle();
+
+    context.switch_account(&accounts.oracle);
+    context.set_prepaid_gas(contract.gas_config.required_for_burn() - Gas(1));
+    contract.burn();
+}
+
+#[test]
+fn test_failed_claim_of_burnt_entry_is_burnt_by_next_burn() {
+    let (mut context, mut contract, accounts) = Context::init_with_oracle();
+    set_test_future_success(EXT_BURN_FUTURE, true);
+
+    context.switch_account(&accounts.oracle);
+    contract.record_batch_for_hold(vec![(accounts.bob.clone(), U128(50_000))]);
+
+    let now = contract.burn_period + 100;
+    context.set_block_timestamp_in_seconds(now as u64);
+    contract.record_batch_for_hold(vec![(accounts.alice.clone(), U128(200_000))]);
+    contract.burn();
+
+    // The transfer of a claim made before the burn fails after the entry has been removed.
+    contract.on_transfer_internal(now, accounts.alice.clone(), 100_000, vec![(0, 100_000)], false);
+
+    assert_eq!(100_000, contract.pending_burn);
+    assert_eq!(300_000, contract.stats.total_held);
+    assert!(contract.get_failed_claim(accounts.alice.clone()).is_none());
+    assert_eq!(
+        200_000,
+        contract.get_claimable_balance_for_account(accounts.alice.clone()).0
+    );
+    assert!(contract.accruals.get((None, 0)).is_none());
+
+    let PromiseOrValue::Value(burnt_amount) = contract.burn() else {
+        panic!("Expected value");
+    };
+
+    assert_eq!(100_000, burnt_amount.0);
+    assert_eq!(0, contract.pending_burn);
+    assert_eq!(200_000, contract.stats.total_held);
+    assert_eq!(150_000, contract.stats.total_burned);
+}
+
+#[test]
+fn test_tokens_returned_during_burn_are_kept_for_next_burn() {
+    let (mut context, mut contract, accounts) = Context::init_with_oracle();
+
+    context.switch_account(&accounts.oracle);
+    contract.record_batch_for_hold(vec![(accounts.alice.clone(), U128(100_000))]);
+
+    context.set_block_timestamp_in_seconds(contract.burn_period as u64 + 100);
+
+    // The burn was scheduled for 60_000 before a failed claim returned 40_000 into the entry.
+    contract.on_burn_internal(60_000, vec![(None, 0)], None, true);
+
+    assert_eq!(40_000, contract.pending_burn);
+    assert_eq!(40_000, contract.stats.total_held);
+    assert_eq!(60_000, contract.stats.total_burned);
+    assert!(contract.accruals.get((None, 0)).is_none());
 }
@@ -1,79 +1,240 @@
+use std::collections::{BTreeMap, HashMap};
+
 use claim_model::{
+    account_history::HistoryEntryKind,
+    account_record::{AccountPeriods, AccountRecord},
     api::ClaimApi,
-    event::{emit, ClaimData, EventKind},
-    ClaimAvailabilityView, ClaimResultView, TokensAmount, UnixTimestamp,
+    error::ClaimError,
+    event::{emit, ClaimData, ClaimFailedData, EventKind},
+    failed_claim::{ClaimFailureReason, FailedClaim},
+    AccountView, ClaimAvailabilityView, ClaimAvailabilityViewV2, ClaimResultView, FailedClaimView, TokensAmount,
+    UnixTimestamp,
 };
-use near_sdk::{env, json_types::U128, near_bindgen, require, store::Vector, AccountId, PromiseOrValue};
+use near_sdk::{env, json_types::U128, near_bindgen, require, AccountId, PromiseOrValue};
 
 use crate::{
-    common::{now_seconds, UnixTimestampExtension},
+    common::{is_lock_active, now_seconds, UnixTimestampExtension},
     Contract, ContractExt,
-    StorageKey::AccrualsEntry,
 };
 
 #[near_bindgen]
 impl ClaimApi for Contract {
     fn get_claimable_balance_for_account(&self, account_id: AccountId) -> U128 {
-        let Some(account_data) = self.accounts.get(&account_id) else {
+        let Some(account_data) = self.account(&account_id) else {
             return U128(0);
         };
 
-        let mut total_accrual = 0;
+        let total_accrual = self
+            .get_live_accruals(&account_data, self.periods(&account_id), now_seconds())
+            .iter()
+            .map(|(_, amount)| amount)
+            .sum();
+
+        U128(total_accrual)
+    }
+
+    fn get_account(&self, account_id: AccountId) -> Option<AccountView> {
+        let account_data = self.account(&account_id)?;
+        let periods = self.periods(&account_id);
         let now = now_seconds();
+        let accruals = self.get_live_accruals(&account_data, periods, now);
+        let claimable_amount = accruals.iter().map(|(_, amount)| amount).sum();
+        let burn_period = periods.burn_period_or(self.burn_period);
+
+        Some(AccountView {
+            accruals: accruals
+                .into_iter()
+                .map(|(datetime, amount)| (datetime, U128(amount), datetime + burn_period))
+                .collect(),
+            claim_period_refreshed_at: account_data.claim_period_refreshed_at,
+            next_claim_at: self.get_next_claim_at(&account_data, periods),
+            is_locked: is_lock_active(account_data.locked_at, now, self.lock_expiry),
+            is_enabled: account_data.is_enabled,
+            claimable_amount: U128(claimable_amount),
+            claim_period: periods.claim_period_or(self.claim_period),
+            burn_period,
+        })
+    }
 
-        for (datetime, index) in &account_data.accruals {
-            if !datetime.is_within_period(now, self.burn_period) {
-                continue;
-            }
+    fn get_expiry_schedule(&self, account_id: AccountId) -> Vec<(UnixTimestamp, U128)> {
+        let Some(account_data) = self.account(&account_id) else {
+            return vec![];
+        };
 
-            let Some((accruals, _)) = self.accruals.get(datetime) else {
-                continue;
-            };
+        let periods = self.periods(&account_id);
+        let burn_period = periods.burn_period_or(self.burn_period);
 
-            if let Some(amount) = accruals.get(*index) {
-                total_accrual += *amount;
-            }
+        let mut schedule = BTreeMap::<UnixTimestamp, TokensAmount>::new();
+        for (datetime, amount) in self.get_live_accruals(&account_data, periods, now_seconds()) {
+            *schedule.entry(datetime + burn_period).or_default() += amount;
         }
 
-        U128(total_accrual)
+        schedule
+            .into_iter()
+            .map(|(burn_at, amount)| (burn_at, U128(amount)))
+            .collect()
+    }
+
+    fn get_accounts_expiring_between(
+        &self,
+        from_ts: UnixTimestamp,
+        to_ts: UnixTimestamp,
+        from_index: u32,
+        limit: u32,
+    ) -> Vec<(AccountId, U128)> {
+        let now = now_seconds();
+
+        self.account_index
+            .page(from_index, limit)
+            .into_iter()
+            .filter_map(|account_id| {
+                let account_data = self.account(&account_id)?;
+                let periods = self.periods(&account_id);
+                let burn_period = periods.burn_period_or(self.burn_period);
+                let amount: TokensAmount = self
+                    .get_live_accruals(&account_data, periods, now)
+                    .iter()
+                    .filter(|(datetime, _)| (from_ts..to_ts).contains(&(datetime + burn_period)))
+                    .map(|(_, amount)| amount)
+                    .sum();
+
+                (amount > 0).then_some((account_id, U128(amount)))
+            })
+            .collect()
     }
 
     fn is_claim_available(&self, account_id: AccountId) -> ClaimAvailabilityView {
-        let Some(account_data) = self.accounts.get(&account_id) else {
+        let Some(account_data) = self.account(&account_id) else {
             return ClaimAvailabilityView::Unregistered;
         };
 
+        if !account_data.is_enabled {
+            return ClaimAvailabilityView::Disabled;
+        }
+
         let claim_period_refreshed_at = account_data.claim_period_refreshed_at;
-        if now_seconds() - claim_period_refreshed_at > self.claim_period {
+        let claim_period = self.periods(&account_id).claim_period_or(self.claim_period);
+        if now_seconds() - claim_period_refreshed_at > claim_period {
             ClaimAvailabilityView::Available
         } else {
-            ClaimAvailabilityView::Unavailable((claim_period_refreshed_at, self.claim_period))
+            ClaimAvailabilityView::Unavailable((claim_period_refreshed_at, claim_period))
         }
     }
 
+    fn is_claim_available_v2(&self, account_id: AccountId) -> ClaimAvailabilityViewV2 {
+        let Some(account_data) = self.account(&account_id) else {
+            return ClaimAvailabilityViewV2::Unregistered;
+        };
+
+        if !account_data.is_enabled {
+            return ClaimAvailabilityViewV2::Disabled;
+        }
+
+        let periods = self.periods(&account_id);
+        let now = now_seconds();
+        let claimable_amount: TokensAmount = self
+            .get_live_accruals(&account_data, periods, now)
+            .iter()
+            .map(|(_, amount)| amount)
+            .sum();
+
+        if claimable_amount == 0 {
+            return ClaimAvailabilityViewV2::Empty;
+        }
+
+        let available_at = self.get_next_claim_at(&account_data, periods);
+        if now >= available_at {
+            ClaimAvailabilityViewV2::Available {
+                claimable_amount: U128(claimable_amount),
+            }
+        } else {
+            ClaimAvailabilityViewV2::Unavailable {
+                available_at,
+                seconds_remaining: available_at - now,
+                claimable_amount: U128(claimable_amount),
+            }
+        }
+    }
+
+    fn get_claimable_balances(&self, account_ids: Vec<AccountId>) -> HashMap<AccountId, U128> {
+        Self::assert_batch_view_size(&account_ids);
+
+        account_ids
+            .into_iter()
+            .map(|account_id| (account_id.clone(), self.get_claimable_balance_for_account(account_id)))
+            .collect()
+    }
+
+    fn get_claim_availabilities(&self, account_ids: Vec<AccountId>) -> HashMap<AccountId, ClaimAvailabilityView> {
+        Self::assert_batch_view_size(&account_ids);
+
+        account_ids
+            .into_iter()
+            .map(|account_id| (account_id.clone(), self.is_claim_available(account_id)))
+            .collect()
+    }
+
     fn claim(&mut self) -> PromiseOrValue<ClaimResultView> {
         let account_id = env::predecessor_account_id();
 
+        Self::assert_enough_gas(self.required_gas_for_claim(&account_id));
+
         require!(
             self.is_claim_available(account_id.clone()) == ClaimAvailabilityView::Available,
-            "Claim is not available at the moment"
+            ClaimError::ClaimUnavailable.to_string()
         );
 
-        let account_data = self.accounts.get_mut(&account_id).expect("Account data is not found");
-        require!(!account_data.is_locked, "Another operation is running");
+        self.claim_internal(account_id)
+    }
 
-        account_data.is_locked = true;
+    fn get_failed_claim(&self, account_id: AccountId) -> Option<FailedClaimView> {
+        self.failed_claims.get(&account_id).map(FailedClaimView::from)
+    }
 
+    fn retry_claim(&mut self) -> PromiseOrValue<ClaimResultView> {
+        let account_id = env::predecessor_account_id();
+
+        Self::assert_enough_gas(self.required_gas_for_claim(&account_id));
+
+        require!(
+            self.failed_claims.contains_key(&account_id),
+            ClaimError::NoFailedClaim.to_string()
+        );
+        require!(
+            self.account(&account_id).is_some_and(|account| account.is_enabled),
+            ClaimError::ClaimUnavailable.to_string()
+        );
+
+        self.claim_internal(account_id)
+    }
+}
+
+impl Contract {
+    /// Moves all live accruals of the account to a transfer. Expects the claim to be allowed.
+    fn claim_internal(&mut self, account_id: AccountId) -> PromiseOrValue<ClaimResultView> {
         let now = now_seconds();
+
+        self.prune_accruals(&account_id, now);
+
+        let burn_override = self.periods(&account_id).burn_period;
+
+        let account_data = self
+            .accounts
+            .get_mut(&account_id)
+            .unwrap_or_else(|| ClaimError::AccountNotFound.panic());
+
+        require!(
+            !is_lock_active(account_data.locked_at, now, self.lock_expiry),
+            ClaimError::AccountLocked.to_string()
+        );
+
+        account_data.locked_at = Some(now);
+
         let mut total_accrual = 0;
         let mut details = vec![];
 
         for (datetime, index) in &account_data.accruals {
-            if !datetime.is_within_period(now, self.burn_period) {
-                continue;
-            }
-
-            let Some((accruals, total)) = self.accruals.get_mut(datetime) else {
+            let Some((accruals, total)) = self.accruals.get_mut((burn_override, *datetime)) else {
                 continue;
             };
 
@@ -89,18 +250,45 @@ impl ClaimApi for Contract {
         }
 
         account_data.accruals.clear();
+        self.stats.release_held(total_accrual);
 
         if total_accrual > 0 {
-            self.transfer_external(now, account_id, total_accrual, details)
+            self.register_storage_and_transfer(now, account_id, total_accrual, details)
         } else {
-            account_data.is_locked = false;
+            account_data.locked_at = None;
+            self.failed_claims.remove(&account_id);
+            self.delete_if_idle(&account_id, now);
+
             PromiseOrValue::Value(ClaimResultView::new(0))
         }
     }
-}
 
-impl Contract {
-    fn on_transfer_internal(
+    /// Returns the earliest moment when the account is able to claim.
+    fn get_next_claim_at(&self, account_data: &AccountRecord, periods: AccountPeriods) -> UnixTimestamp {
+        account_data.claim_period_refreshed_at + periods.claim_period_or(self.claim_period) + 1
+    }
+
+    /// Returns timestamps and amounts of the account's accruals which are not burnt yet.
+    pub(crate) fn get_live_accruals(
+        &self,
+        account_data: &AccountRecord,
+        periods: AccountPeriods,
+        now: UnixTimestamp,
+    ) -> Vec<(UnixTimestamp, TokensAmount)> {
+        let burn_period = periods.burn_period_or(self.burn_period);
+
+        account_data
+            .accruals
+            .iter()
+            .filter(|(datetime, _)| datetime.is_within_period(now, burn_period))
+            .filter_map(|(datetime, index)| {
+                let (accruals, _) = self.accruals.get((periods.burn_period, *datetime))?;
+                accruals.get(*index).map(|amount| (*datetime, *amount))
+            })
+            .collect()
+    }
+
+    pub(crate) fn on_transfer_internal(
         &mut self,
         now: UnixTimestamp,
         account_id: AccountId,
@@ -108,37 +296,103 @@ impl Contract {
         details: Vec<(UnixTimestamp, TokensAmount)>,
         is_success: bool,
     ) -> ClaimResultView {
-        let account = self.accounts.get_mut(&account_id).expect("Account not found");
-        account.is_locked = false;
+        if !is_success {
+            return self.fail_claim(
+                now,
+                account_id,
+                total_accrual,
+                details,
+                ClaimFailureReason::TransferFailed,
+            );
+        }
 
-        if is_success {
+        // The tokens have left the contract even if the account has been removed by `clean`
+        // after its lock had expired, so the claim is still accounted and reported.
+        if let Some(account) = self.accounts.get_mut(&account_id) {
+            account.locked_at = None;
             account.claim_period_refreshed_at = now;
 
-            let event_data = ClaimData {
-                account_id,
-                details: details
-                    .iter()
-                    .map(|(timestamp, amount)| (*timestamp, U128(*amount)))
-                    .collect(),
-                total_claimed: U128(total_accrual),
-            };
-            emit(EventKind::Claim(event_data));
+            self.push_history(&account_id, HistoryEntryKind::Claim, total_accrual, now);
+        }
+
+        self.failed_claims.remove(&account_id);
+        self.stats.total_claimed += total_accrual;
+
+        let event_data = ClaimData {
+            account_id,
+            details: details
+                .iter()
+                .map(|(timestamp, amount)| (*timestamp, U128(*amount)))
+                .collect(),
+            total_claimed: U128(total_accrual),
+            chunk: None,
+        };
+        emit(EventKind::Claim(event_data));
+
+        ClaimResultView::new(total_accrual)
+    }
+
+    /// Returns the tokens of a claim which hasn't reached the account back to it
+    /// and records the failure for `retry_claim`.
+    pub(crate) fn fail_claim(
+        &mut self,
+        now: UnixTimestamp,
+        account_id: AccountId,
+        total_accrual: TokensAmount,
+        details: Vec<(UnixTimestamp, TokensAmount)>,
+        reason: ClaimFailureReason,
+    ) -> ClaimResultView {
+        let burn_override = self.periods(&account_id).burn_period;
 
-            return ClaimResultView::new(total_accrual);
+        let mut account = self.accounts.get_mut(&account_id);
+        if let Some(account) = account.as_mut() {
+            account.locked_at = None;
         }
 
+        let mut total_returned = 0;
         for (timestamp, amount) in details {
-            let daily_accruals = self
-                .accruals
-                .entry(timestamp)
-                .or_insert_with(|| (Vector::new(AccrualsEntry(timestamp)), 0));
+            self.stats.total_held += amount;
+
+            // `burn` could have removed the entry while the transfer was in flight. Its tokens are
+            // expired, so they go to the next burn instead of a recreated entry. The same applies
+            // to tokens of an account removed by `clean` after its lock has expired.
+            let (Some(account), Some((accruals, total))) =
+                (account.as_mut(), self.accruals.get_mut((burn_override, timestamp)))
+            else {
+                self.pending_burn += amount;
+                continue;
+            };
+
+            accruals.push(amount);
+            *total += amount;
+            total_returned += amount;
 
-            daily_accruals.0.push(amount);
-            daily_accruals.1 += amount;
+            account.accruals.push((timestamp, accruals.len() - 1));
+        }
 
-            account.accruals.push((timestamp, daily_accruals.0.len() - 1));
+        let attempts = self.failed_claims.get(&account_id).map_or(0, |failed| failed.attempts) + 1;
+        // Tokens sent to `pending_burn` can't be retried, so only the returned ones are kept for `retry_claim`.
+        if total_returned > 0 {
+            self.failed_claims.insert(
+                account_id.clone(),
+                FailedClaim {
+                    amount: total_returned,
+                    failed_at: now,
+                    reason,
+                    attempts,
+                },
+            );
+        } else {
+            self.failed_claims.remove(&account_id);
         }
 
+        emit(EventKind::ClaimFailed(ClaimFailedData {
+            account_id,
+            amount: U128(total_accrual),
+            reason,
+            attempts,
+        }));
+
         ClaimResultView::new(0)
     }
 }
@@ -147,7 +401,8 @@ impl Contract {
 mod prod {
     use claim_model::{ClaimResultView, TokensAmount, UnixTimestamp};
     use near_sdk::{
-        env, ext_contract, is_promise_success, near_bindgen, serde_json::json, AccountId, Gas, Promise, PromiseOrValue,
+        env, ext_contract, is_promise_success, near_bindgen, serde_json::json, AccountId, GasWeight, Promise,
+        PromiseOrValue,
     };
 
     use crate::{Contract, ContractExt};
@@ -194,11 +449,20 @@ mod prod {
             .as_bytes()
             .to_vec();
 
+            // The callback gets its minimum gas plus everything left of the prepaid gas
+            // once this call finishes, while the transfer gets exactly the configured amount.
             Promise::new(self.token_account_id.clone())
-                .function_call("ft_transfer".to_string(), args, 1, Gas(5 * Gas::ONE_TERA.0))
+                .function_call_weight(
+                    "ft_transfer".to_string(),
+                    args,
+                    1,
+                    self.gas_config.transfer,
+                    GasWeight(0),
+                )
                 .then(
                     ext_self::ext(env::current_account_id())
-                        .with_static_gas(Gas(5 * Gas::ONE_TERA.0))
+                        .with_static_gas(self.gas_config.transfer_callback)
+                        .with_unused_gas_weight(1)
                         .on_transfer(now, account_id, total_accrual, details),
                 )
                 .into()
@@ -2,9 +2,10 @@
 
 use claim_model::{
     api::{ClaimApi, RecordApi},
-    ClaimAvailabilityView, UnixTimestamp,
+    failed_claim::ClaimFailureReason,
+    ClaimAvailabilityView, ClaimAvailabilityViewV2, FailedClaimView, UnixTimestamp, MAX_BATCH_VIEW_SIZE,
 };
-use near_sdk::{json_types::U128, PromiseOrValue};
+use near_sdk::{json_types::U128, Gas, PromiseOrValue};
 
 use crate::{
     claim::api::test::EXT_TRANSFER_FUTURE,
@@ -133,6 +134,21 @@ fn test_claim_when_user_has_tokens_and_claim_period_is_not_passed() {
     contract.claim();
 }
 
+#[test]
+#[should_panic(expected = "Not enough gas attached")]
+fn test_claim_with_not_enough_gas() {
+    let (mut context, mut contract, accounts) = Context::init_with_oracle();
+    set_test_future_success(EXT_TRANSFER_FUTURE, true);
+
+    context.switch_account(&accounts.oracle);
+    contract.record_batch_for_hold(vec![(accounts.alice.clone(), U128(200_000))]);
+
+    context.set_block_timestamp_in_seconds(contract.claim_period as u64 + 1);
+    context.switch_account(&accounts.alice);
+    context.set_prepaid_gas(contract.gas_config.required_for_claim() - Gas(1));
+    contract.claim();
+}
+
 #[test]
 fn test_claim_when_user_has_tokens_and_current_time_matches_claim_period() {
     let (mut context, mut contract, accounts) = Context::init_with_oracle();
@@ -215,3 +231,247 @@ fn test_claim_when_user_has_tokens_and_claim_period_is_passed_and_transfer_faile
     let alice_new_balance = contract.get_claimable_balance_for_account(accounts.alice.clone()).0;
     assert_eq!(alice_balance, alice_new_balance);
 }
+
+#[test]
+fn test_get_account_when_user_is_not_registered() {
+    let (_, contract, accounts) = Context::init_with_oracle();
+
+    assert!(contract.get_account(accounts.alice).is_none());
+}
+
+#[test]
+fn test_get_account_when_user_has_tokens() {
+    let (mut context, mut contract, accounts) = Context::init_with_oracle();
+
+    context.switch_account(&accounts.oracle);
+    contract.record_batch_for_hold(vec![(accounts.alice.clone(), U128(100_000))]);
+
+    let second_record_timestamp = contract.claim_period * 2;
+    context.set_block_timestamp_in_seconds(second_record_timestamp as u64);
+    contract.record_batch_for_hold(vec![(accounts.alice.clone(), U128(200_000))]);
+
+    context.set_block_timestamp_in_seconds(contract.burn_period as u64 + 100);
+
+    let account = contract.get_account(accounts.alice.clone()).unwrap();
+    assert_eq!(
+        vec![(
+            second_record_timestamp,
+            U128(200_000),
+            second_record_timestamp + contract.burn_period
+        )],
+        account.accruals
+    );
+    assert_eq!(0, account.claim_period_refreshed_at);
+    assert_eq!(contract.claim_period + 1, account.next_claim_at);
+    assert!(!account.is_locked);
+    assert!(account.is_enabled);
+    assert_eq!(200_000, account.claimable_amount.0);
+}
+
+#[test]
+fn test_get_expiry_schedule() {
+    let (mut context, mut contract, accounts) = Context::init_with_oracle();
+
+    context.switch_account(&accounts.oracle);
+    contract.record_batch_for_hold(vec![
+        (accounts.alice.clone(), U128(100_000)),
+        (accounts.alice.clone(), U128(50_000)),
+    ]);
+
+    context.set_block_timestamp_in_seconds(1_000);
+    contract.record_batch_for_hold(vec![(accounts.alice.clone(), U128(200_000))]);
+
+    let schedule = contract.get_expiry_schedule(accounts.alice.clone());
+    assert_eq!(
+        vec![
+            (contract.burn_period, U128(150_000)),
+            (1_000 + contract.burn_period, U128(200_000)),
+        ],
+        schedule
+    );
+
+    context.set_block_timestamp_in_seconds(contract.burn_period as u64);
+
+    let schedule = contract.get_expiry_schedule(accounts.alice.clone());
+    assert_eq!(vec![(1_000 + contract.burn_period, U128(200_000))], schedule);
+
+    assert!(contract.get_expiry_schedule(accounts.bob).is_empty());
+}
+
+#[test]
+fn test_get_accounts_expiring_between() {
+    let (mut context, mut contract, accounts) = Context::init_with_oracle();
+
+    context.switch_account(&accounts.oracle);
+    contract.record_batch_for_hold(vec![
+        (accounts.alice.clone(), U128(100_000)),
+        (accounts.bob.clone(), U128(200_000)),
+    ]);
+
+    context.set_block_timestamp_in_seconds(1_000);
+    contract.record_batch_for_hold(vec![(accounts.alice.clone(), U128(300_000))]);
+
+    let burn_period = contract.burn_period;
+
+    let expiring = contract.get_accounts_expiring_between(0, burn_period + 1, 0, 10);
+    assert_eq!(
+        vec![
+            (accounts.alice.clone(), U128(100_000)),
+            (accounts.bob.clone(), U128(200_000)),
+        ],
+        expiring
+    );
+
+    let expiring = contract.get_accounts_expiring_between(0, burn_period + 1_001, 1, 10);
+    assert_eq!(vec![(accounts.bob.clone(), U128(200_000))], expiring);
+
+    let expiring = contract.get_accounts_expiring_between(burn_period + 1, burn_period + 1_001, 0, 10);
+    assert_eq!(vec![(accounts.alice.clone(), U128(300_000))], expiring);
+}
+
+#[test]
+fn test_get_claimable_balances_and_availabilities() {
+    let (mut context, mut contract, accounts) = Context::init_with_oracle();
+
+    context.switch_account(&accounts.oracle);
+    contract.record_batch_for_hold(vec![(accounts.alice.clone(), U128(100_000))]);
+
+    context.set_block_timestamp_in_seconds(contract.claim_period as u64 + 100);
+
+    let account_ids = vec![accounts.alice.clone(), accounts.bob.clone()];
+
+    let balances = contract.get_claimable_balances(account_ids.clone());
+    assert_eq!(2, balances.len());
+    assert_eq!(U128(100_000), balances[&accounts.alice]);
+    assert_eq!(U128(0), balances[&accounts.bob]);
+
+    let availabilities = contract.get_claim_availabilities(account_ids);
+    assert_eq!(2, availabilities.len());
+    assert_eq!(ClaimAvailabilityView::Available, availabilities[&accounts.alice]);
+    assert_eq!(ClaimAvailabilityView::Unregistered, availabilities[&accounts.bob]);
+}
+
+#[test]
+#[should_panic(expected = "Batch contains too many accounts")]
+fn test_get_claimable_balances_with_too_large_batch() {
+    let (_, contract, accounts) = Context::init_with_oracle();
+
+    contract.get_claimable_balances(vec![accounts.alice; MAX_BATCH_VIEW_SIZE + 1]);
+}
+
+#[test]
+fn test_check_claim_availability_v2() {
+    let (mut context, mut contract, accounts) = Context::init_with_oracle();
+    set_test_future_success(EXT_TRANSFER_FUTURE, true);
+
+    assert_eq!(
+        ClaimAvailabilityViewV2::Unregistered,
+        contract.is_claim_available_v2(accounts.alice.clone())
+    );
+
+    let alice_balance = 400_000;
+    context.switch_account(&accounts.oracle);
+    contract.record_batch_for_hold(vec![(accounts.alice.clone(), U128(alice_balance))]);
+
+    context.set_block_timestamp_in_seconds(100);
+
+    let available_at = contract.claim_period + 1;
+    assert_eq!(
+        ClaimAvailabilityViewV2::Unavailable {
+            available_at,
+            seconds_remaining: available_at - 100,
+            claimable_amount: U128(alice_balance),
+        },
+        contract.is_claim_available_v2(accounts.alice.clone())
+    );
+
+    context.set_block_timestamp_in_seconds(available_at as u64);
+    assert_eq!(
+        ClaimAvailabilityViewV2::Available {
+            claimable_amount: U128(alice_balance),
+        },
+        contract.is_claim_available_v2(accounts.alice.clone())
+    );
+    assert_eq!(
+        ClaimAvailabilityView::Available,
+        contract.is_claim_available(accounts.alice.clone())
+    );
+
+    context.switch_account(&accounts.alice);
+    contract.claim();
+
+    assert_eq!(
+        ClaimAvailabilityViewV2::Empty,
+        contract.is_claim_available_v2(accounts.alice.clone())
+    );
+}
+
+#[test]
+fn test_failed_claim_is_recorded_and_retried() {
+    let (mut context, mut contract, accounts) = Context::init_with_oracle();
+    set_test_future_success(EXT_TRANSFER_FUTURE, false);
+
+    let alice_balance = 400_000;
+    context.switch_account(&accounts.oracle);
+    contract.record_batch_for_hold(vec![(accounts.alice.clone(), U128(alice_balance))]);
+
+    let claim_timestamp = contract.claim_period as u64 + 100;
+    context.set_block_timestamp_in_seconds(claim_timestamp);
+    context.switch_account(&accounts.alice);
+    contract.claim();
+    contract.claim();
+
+    let failed_claim = contract.get_failed_claim(accounts.alice.clone()).unwrap();
+    assert_eq!(
+        FailedClaimView {
+            amount: U128(alice_balance),
+            failed_at: claim_timestamp as UnixTimestamp,
+            reason: ClaimFailureReason::TransferFailed,
+            attempts: 2,
+        },
+        failed_claim
+    );
+
+    set_test_future_success(EXT_TRANSFER_FUTURE, true);
+    let PromiseOrValue::Value(result) = contract.retry_claim() else {
+        panic!("Expected value");
+    };
+
+    assert_eq!(alice_balance, result.total.0);
+    assert!(contract.get_failed_claim(accounts.alice.clone()).is_none());
+    assert_eq!(0, contract.get_claimable_balance_for_account(accounts.alice).0);
+}
+
+#[test]
+fn test_retry_claim_of_expired_tokens_removes_failed_claim() {
+    let (mut context, mut contract, accounts) = Context::init_with_oracle();
+    set_test_future_success(EXT_TRANSFER_FUTURE, false);
+
+    context.switch_account(&accounts.oracle);
+    contract.record_batch_for_hold(vec![(accounts.alice.clone(), U128(400_000))]);
+
+    context.set_block_timestamp_in_seconds(contract.claim_period as u64 + 100);
+    context.switch_account(&accounts.alice);
+    contract.claim();
+    assert!(contract.get_failed_claim(accounts.alice.clone()).is_some());
+
+    context.set_block_timestamp_in_seconds(contract.burn_period as u64 + 100);
+    let PromiseOrValue::Value(result) = contract.retry_claim() else {
+        panic!("Expected value");
+    };
+
+    assert_eq!(0, result.total.0);
+    assert!(contract.get_failed_claim(accounts.alice).is_none());
+}
+
+#[test]
+#[should_panic(expected = "No failed claim to retry")]
+fn test_retry_claim_without_failed_claim() {
+    let (mut context, mut contract, accounts) = Context::init_with_oracle();
+
+    context.switch_account(&accounts.oracle);
+    contract.record_batch_for_hold(vec![(accounts.alice.clone(), U128(400_000))]);
+
+    context.switch_account(&accounts.alice);
+    contract.retry_claim();
+}
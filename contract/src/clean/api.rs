@@ -1,21 +1,117 @@
-use claim_model::event::{emit, CleanData, EventKind};
-use near_sdk::{near_bindgen, AccountId};
+use claim_model::{
+    api::{ClaimApi, CleanApi},
+    event::{emit, CleanData, EventKind},
+    TokensAmount, UnixTimestamp,
+};
+use near_sdk::{json_types::U128, near_bindgen, AccountId};
 
-use crate::{Contract, ContractExt};
-
-pub trait CleanApi {
-    fn clean(&mut self, account_ids: Vec<AccountId>);
-}
+use crate::{
+    common::{is_lock_active, now_seconds, UnixTimestampExtension},
+    Contract, ContractExt,
+};
 
 #[near_bindgen]
 impl CleanApi for Contract {
     fn clean(&mut self, account_ids: Vec<AccountId>) {
         self.assert_oracle();
 
-        for account_id in account_ids.clone() {
-            self.accounts.set(account_id, None);
+        self.clean_internal(account_ids);
+    }
+
+    fn clean_paginated(&mut self, from_index: u32, limit: u32) -> Option<u32> {
+        self.assert_oracle();
+        Self::assert_event_batch_size(limit as usize);
+
+        self.clean_internal(self.account_index.page(from_index, limit));
+
+        self.account_index.next_page(from_index, limit)
+    }
+
+    fn clean_preview(&self, account_ids: Vec<AccountId>) -> Vec<(AccountId, U128)> {
+        Self::assert_event_batch_size(account_ids.len());
+
+        let now = now_seconds();
+
+        account_ids
+            .into_iter()
+            .filter(|account_id| self.is_cleanable(account_id, now))
+            .map(|account_id| (account_id.clone(), self.get_claimable_balance_for_account(account_id)))
+            .collect()
+    }
+}
+
+impl Contract {
+    fn clean_internal(&mut self, account_ids: Vec<AccountId>) {
+        Self::assert_event_batch_size(account_ids.len());
+
+        let now = now_seconds();
+        let mut cleaned_ids = Vec::with_capacity(account_ids.len());
+        let mut amounts = Vec::with_capacity(account_ids.len());
+
+        for account_id in account_ids {
+            if !self.is_cleanable(&account_id, now) {
+                continue;
+            }
+
+            let amount = self.revoke_live_accruals(&account_id, now);
+            if amount > 0 {
+                self.stats.total_revoked += amount;
+            }
+            amounts.push(U128(amount));
+
+            self.remove_account(&account_id);
+            cleaned_ids.push(account_id);
+        }
+
+        emit(EventKind::Clean(CleanData {
+            account_ids: cleaned_ids,
+            amounts,
+            chunk: None,
+        }));
+    }
+
+    /// Checks whether `clean` removes the account. The tokens of a claim in flight are returned
+    /// to the account if the transfer fails, so an account is kept until its claim completes.
+    fn is_cleanable(&self, account_id: &AccountId, now: UnixTimestamp) -> bool {
+        !self
+            .account(account_id)
+            .is_some_and(|account| is_lock_active(account.locked_at, now, self.lock_expiry))
+    }
+
+    /// Zeroes the account's live entries in `accruals` and moves their tokens to `pending_burn`.
+    /// Expired entries are left to be burnt along with their buckets.
+    fn revoke_live_accruals(&mut self, account_id: &AccountId, now: UnixTimestamp) -> TokensAmount {
+        self.upgrade_account(account_id);
+
+        let burn_override = self.periods(account_id).burn_period;
+        let burn_period = burn_override.unwrap_or(self.burn_period);
+
+        let Some(account) = self.accounts.get(account_id) else {
+            return 0;
+        };
+
+        let mut total_revoked = 0;
+
+        for (datetime, index) in &account.accruals {
+            if !datetime.is_within_period(now, burn_period) {
+                continue;
+            }
+
+            let Some((accruals, total)) = self.accruals.get_mut((burn_override, *datetime)) else {
+                continue;
+            };
+
+            let Some(amount) = accruals.get_mut(*index) else {
+                continue;
+            };
+
+            total_revoked += *amount;
+            *total -= *amount;
+            *amount = 0;
         }
 
-        emit(EventKind::Clean(CleanData { account_ids }));
+        self.pending_burn += total_revoked;
+
+        total_revoked
     }
 }
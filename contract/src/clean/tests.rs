@@ -1,9 +1,15 @@
 #![cfg(test)]
 
-use claim_model::api::RecordApi;
-use near_sdk::json_types::U128;
+use claim_model::{
+    api::{BurnApi, CleanApi, InvariantApi, RecordApi},
+    event::MAX_EVENT_BATCH_SIZE,
+};
+use near_sdk::{json_types::U128, test_utils::get_logs, PromiseOrValue};
 
-use crate::{clean::api::CleanApi, common::tests::Context};
+use crate::{
+    burn::api::test::EXT_BURN_FUTURE,
+    common::tests::{data::set_test_future_success, Context},
+};
 
 #[test]
 fn test_clean_single_account_by_oracle() {
@@ -38,6 +44,15 @@ fn test_clean_single_account_by_not_oracle() {
     contract.clean(vec![accounts.alice.clone()]);
 }
 
+#[test]
+#[should_panic(expected = "Batch contains too many accounts")]
+fn test_clean_too_large_batch() {
+    let (mut context, mut contract, accounts) = Context::init_with_oracle();
+    context.switch_account(&accounts.oracle);
+
+    contract.clean(vec![accounts.alice.clone(); MAX_EVENT_BATCH_SIZE + 1]);
+}
+
 #[test]
 fn test_clean_multiple_accounts_by_oracle() {
     let (mut context, mut contract, accounts) = Context::init_with_oracle();
@@ -64,3 +79,172 @@ fn test_clean_multiple_accounts_by_oracle() {
     let bob_record = contract.accounts.get(&accounts.bob);
     assert!(bob_record.is_none());
 }
+
+#[test]
+fn test_clean_moves_live_accruals_to_pending_burn() {
+    let (mut context, mut contract, accounts) = Context::init_with_oracle();
+    set_test_future_success(EXT_BURN_FUTURE, true);
+    context.switch_account(&accounts.oracle);
+
+    contract.record_batch_for_hold(vec![
+        (accounts.alice.clone(), U128(100_000)),
+        (accounts.bob.clone(), U128(200_000)),
+    ]);
+
+    contract.clean(vec![accounts.alice.clone()]);
+
+    let logs = get_logs();
+    assert!(logs
+        .last()
+        .unwrap()
+        .contains(r#""data":{"account_ids":["alice"],"amounts":["100000"]}"#));
+
+    let (_, bucket_total) = contract.accruals.get((None, 0)).unwrap();
+    assert_eq!(200_000, *bucket_total);
+    assert_eq!(100_000, contract.pending_burn);
+    assert_eq!(300_000, contract.stats.total_held);
+    assert_eq!(100_000, contract.stats.total_revoked);
+    assert!(contract.check_invariants(None, 10).is_ok());
+
+    let PromiseOrValue::Value(burnt_amount) = contract.burn() else {
+        panic!("Expected value");
+    };
+
+    assert_eq!(100_000, burnt_amount.0);
+    assert_eq!(0, contract.pending_burn);
+    assert_eq!(200_000, contract.stats.total_held);
+
+    // Revoked tokens are counted as burnt once burnt, so they aren't a separate term of the balance.
+    assert_eq!(100_000, contract.stats.total_burned);
+    assert_eq!(100_000, contract.stats.total_revoked);
+    assert_eq!(
+        contract.stats.total_recorded,
+        contract.stats.total_claimed + contract.stats.total_burned + contract.stats.total_held
+    );
+}
+
+#[test]
+fn test_clean_skips_locked_account() {
+    let (mut context, mut contract, accounts) = Context::init_with_oracle();
+    context.switch_account(&accounts.oracle);
+
+    contract.record_batch_for_hold(vec![
+        (accounts.alice.clone(), U128(100_000)),
+        (accounts.bob.clone(), U128(200_000)),
+    ]);
+    contract.accounts.get_mut(&accounts.alice).unwrap().locked_at = Some(0);
+
+    contract.clean(vec![accounts.alice.clone(), accounts.bob.clone()]);
+
+    assert!(contract.accounts.get(&accounts.alice).is_some());
+    assert!(contract.accounts.get(&accounts.bob).is_none());
+    assert_eq!(200_000, contract.pending_burn);
+
+    let logs = get_logs();
+    assert!(!logs.last().unwrap().contains(accounts.alice.as_str()));
+}
+
+#[test]
+fn test_failed_claim_of_cleaned_account_goes_to_pending_burn() {
+    let (mut context, mut contract, accounts) = Context::init_with_oracle();
+    context.switch_account(&accounts.oracle);
+
+    contract.record_batch_for_hold(vec![(accounts.alice.clone(), U128(100_000))]);
+    contract.clean(vec![accounts.alice.clone()]);
+
+    // The transfer of a claim whose lock has expired fails after the account has been cleaned.
+    contract.on_transfer_internal(0, accounts.alice.clone(), 20_000, vec![(0, 20_000)], false);
+
+    assert!(contract.accounts.get(&accounts.alice).is_none());
+    assert!(contract.failed_claims.get(&accounts.alice).is_none());
+    assert_eq!(120_000, contract.pending_burn);
+    assert_eq!(120_000, contract.stats.total_held);
+}
+
+#[test]
+fn test_successful_claim_of_cleaned_account_is_accounted() {
+    let (mut context, mut contract, accounts) = Context::init_with_oracle();
+    context.switch_account(&accounts.oracle);
+
+    contract.record_batch_for_hold(vec![(accounts.alice.clone(), U128(100_000))]);
+    contract.clean(vec![accounts.alice.clone()]);
+
+    // The transfer of a claim whose lock has expired succeeds after the account has been cleaned.
+    contract.on_transfer_internal(0, accounts.alice.clone(), 20_000, vec![(0, 20_000)], true);
+
+    assert!(contract.accounts.get(&accounts.alice).is_none());
+    assert_eq!(20_000, contract.stats.total_claimed);
+
+    let logs = get_logs();
+    assert!(logs.last().unwrap().contains(r#""event":"claim""#));
+    assert!(logs.last().unwrap().contains(r#""total_claimed":"20000""#));
+}
+
+#[test]
+fn test_clean_paginated() {
+    let (mut context, mut contract, accounts) = Context::init_with_oracle();
+    context.switch_account(&accounts.oracle);
+
+    contract.record_batch_for_hold(vec![
+        (accounts.alice.clone(), U128(100_000)),
+        (accounts.bob.clone(), U128(200_000)),
+    ]);
+
+    assert_eq!(Some(1), contract.clean_paginated(0, 1));
+    assert!(contract.accounts.get(&accounts.alice).is_none());
+    assert!(contract.accounts.get(&accounts.bob).is_some());
+
+    assert_eq!(None, contract.clean_paginated(1, 1));
+    assert!(contract.accounts.get(&accounts.bob).is_none());
+    assert_eq!(300_000, contract.pending_burn);
+}
+
+#[test]
+fn test_clean_preview() {
+    let (mut context, mut contract, accounts) = Context::init_with_oracle();
+    context.switch_account(&accounts.oracle);
+
+    contract.record_batch_for_hold(vec![(accounts.alice.clone(), U128(100_000))]);
+
+    let preview = contract.clean_preview(vec![accounts.alice.clone(), accounts.bob.clone()]);
+
+    assert_eq!(
+        vec![(accounts.alice.clone(), U128(100_000)), (accounts.bob.clone(), U128(0))],
+        preview
+    );
+    assert!(contract.accounts.get(&accounts.alice).is_some());
+    assert_eq!(0, contract.pending_burn);
+}
+
+#[test]
+fn test_clean_preview_skips_locked_account() {
+    let (mut context, mut contract, accounts) = Context::init_with_oracle();
+    context.switch_account(&accounts.oracle);
+
+    contract.record_batch_for_hold(vec![
+        (accounts.alice.clone(), U128(100_000)),
+        (accounts.bob.clone(), U128(200_000)),
+    ]);
+    contract.accounts.get_mut(&accounts.alice).unwrap().locked_at = Some(0);
+
+    let preview = contract.clean_preview(vec![accounts.alice.clone(), accounts.bob.clone()]);
+
+    assert_eq!(vec![(accounts.bob.clone(), U128(200_000))], preview);
+}
+
+#[test]
+#[should_panic(expected = "Batch contains too many accounts")]
+fn test_clean_preview_too_large_batch() {
+    let (_, contract, accounts) = Context::init_with_oracle();
+
+    contract.clean_preview(vec![accounts.alice; MAX_EVENT_BATCH_SIZE + 1]);
+}
+
+#[test]
+#[should_panic(expected = "Batch contains too many accounts")]
+fn test_clean_paginated_too_large_page() {
+    let (mut context, mut contract, accounts) = Context::init_with_oracle();
+    context.switch_account(&accounts.oracle);
+
+    contract.clean_paginated(0, MAX_EVENT_BATCH_SIZE as u32 + 1);
+}
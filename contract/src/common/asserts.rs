@@ -1,6 +1,7 @@
+use claim_model::{error::ClaimError, event::MAX_EVENT_BATCH_SIZE, MAX_BATCH_VIEW_SIZE};
 use near_sdk::{
-    env::{current_account_id, predecessor_account_id},
-    require,
+    env::{current_account_id, predecessor_account_id, prepaid_gas},
+    require, AccountId, Gas,
 };
 
 use crate::Contract;
@@ -9,11 +10,29 @@ impl Contract {
     pub(crate) fn assert_oracle(&self) {
         require!(
             self.oracles.contains(&predecessor_account_id()),
-            "Unauthorized access! Only oracle can do this!"
+            ClaimError::Unauthorized.to_string()
         );
     }
 
     pub(crate) fn assert_private() {
-        require!(current_account_id() == predecessor_account_id(), "Method is private",);
+        require!(
+            current_account_id() == predecessor_account_id(),
+            ClaimError::PrivateMethod.to_string()
+        );
+    }
+
+    pub(crate) fn assert_enough_gas(required: Gas) {
+        require!(prepaid_gas() >= required, ClaimError::NotEnoughGas.to_string());
+    }
+
+    pub(crate) fn assert_batch_view_size(account_ids: &[AccountId]) {
+        require!(
+            account_ids.len() <= MAX_BATCH_VIEW_SIZE,
+            ClaimError::BatchTooLarge.to_string()
+        );
+    }
+
+    pub(crate) fn assert_event_batch_size(len: usize) {
+        require!(len <= MAX_EVENT_BATCH_SIZE, ClaimError::BatchTooLarge.to_string());
     }
 }
@@ -0,0 +1,97 @@
+use claim_model::{Duration, TokensAmount, UnixTimestamp};
+use near_sdk::{
+    borsh::{self, BorshDeserialize, BorshSerialize},
+    store::{UnorderedMap, Vector},
+};
+
+use crate::StorageKey;
+
+/// Accrual entries recorded at the same time and the total of their amounts.
+pub(crate) type Bucket = (Vector<TokensAmount>, TokensAmount);
+
+/// The burn period override of the accounts whose accruals are in a bucket, `None` for
+/// `Contract.burn_period`, and the timestamp when the bucket was recorded.
+pub(crate) type BucketKey = (Option<Duration>, UnixTimestamp);
+
+/// Buckets of accruals split by the burn period of their accounts.
+///
+/// A bucket is burnt once the burn period of its accounts has passed, so accounts with a burn
+/// period override get buckets of their own and don't hold back burning of the other buckets.
+/// Buckets of the contract-wide burn period keep the layout and the storage prefix of the previous version.
+#[derive(BorshDeserialize, BorshSerialize)]
+pub(crate) struct Buckets {
+    ordinary: UnorderedMap<UnixTimestamp, Bucket>,
+    overridden: UnorderedMap<(Duration, UnixTimestamp), Bucket>,
+}
+
+impl Buckets {
+    pub(crate) fn new(ordinary: UnorderedMap<UnixTimestamp, Bucket>, overridden_prefix: StorageKey) -> Self {
+        Self {
+            ordinary,
+            overridden: UnorderedMap::new(overridden_prefix),
+        }
+    }
+
+    /// Returns the number of buckets.
+    pub(crate) fn len(&self) -> u32 {
+        self.ordinary.len() + self.overridden.len()
+    }
+
+    pub(crate) fn get(&self, (burn_period, timestamp): BucketKey) -> Option<&Bucket> {
+        match burn_period {
+            None => self.ordinary.get(&timestamp),
+            Some(burn_period) => self.overridden.get(&(burn_period, timestamp)),
+        }
+    }
+
+    pub(crate) fn get_mut(&mut self, (burn_period, timestamp): BucketKey) -> Option<&mut Bucket> {
+        match burn_period {
+            None => self.ordinary.get_mut(&timestamp),
+            Some(burn_period) => self.overridden.get_mut(&(burn_period, timestamp)),
+        }
+    }
+
+    /// Returns the bucket, creating an empty one if there is none. The flag is `true` if the bucket was created.
+    pub(crate) fn get_or_create(&mut self, (burn_period, timestamp): BucketKey) -> (&mut Bucket, bool) {
+        let mut is_created = false;
+
+        let bucket = match burn_period {
+            None => self.ordinary.entry(timestamp).or_insert_with(|| {
+                is_created = true;
+                (Vector::new(StorageKey::AccrualsEntry(timestamp)), 0)
+            }),
+            Some(burn_period) => self.overridden.entry((burn_period, timestamp)).or_insert_with(|| {
+                is_created = true;
+                (
+                    Vector::new(StorageKey::OverriddenAccrualsEntry(burn_period, timestamp)),
+                    0,
+                )
+            }),
+        };
+
+        (bucket, is_created)
+    }
+
+    pub(crate) fn remove(&mut self, (burn_period, timestamp): BucketKey) -> Option<Bucket> {
+        match burn_period {
+            None => self.ordinary.remove(&timestamp),
+            Some(burn_period) => self.overridden.remove(&(burn_period, timestamp)),
+        }
+    }
+
+    /// Checks whether any bucket has been recorded at the timestamp.
+    pub(crate) fn contains_timestamp(&self, timestamp: UnixTimestamp) -> bool {
+        self.ordinary.contains_key(&timestamp) || self.overridden.keys().any(|(_, datetime)| *datetime == timestamp)
+    }
+
+    pub(crate) fn iter(&self) -> impl Iterator<Item = (BucketKey, &Bucket)> {
+        self.ordinary
+            .iter()
+            .map(|(timestamp, bucket)| ((None, *timestamp), bucket))
+            .chain(
+                self.overridden
+                    .iter()
+                    .map(|((burn_period, timestamp), bucket)| ((Some(*burn_period), *timestamp), bucket)),
+            )
+    }
+}
@@ -0,0 +1,99 @@
+use near_sdk::{
+    borsh::{self, BorshDeserialize, BorshSerialize},
+    store::LookupMap,
+};
+
+use crate::StorageKey;
+
+/// An iterable index over the keys of a map which itself can't be enumerated cheaply,
+/// such as `Contract.accounts` or `Contract.accruals`.
+///
+/// Every indexed key gets a position after the positions of all keys indexed before it.
+/// Positions are never changed or reused, so a page of positions lists the same keys however
+/// other keys are added or removed. Removed keys leave gaps, so a page can contain fewer
+/// keys than positions. Gaps at the beginning are skipped by moving `start`.
+#[derive(BorshDeserialize, BorshSerialize)]
+pub(crate) struct PositionIndex<T: BorshSerialize + BorshDeserialize + Ord + Clone> {
+    keys: LookupMap<u32, T>,
+    positions: LookupMap<T, u32>,
+    start: u32,
+    end: u32,
+    len: u32,
+}
+
+impl<T: BorshSerialize + BorshDeserialize + Ord + Clone> PositionIndex<T> {
+    pub(crate) fn new(keys_prefix: StorageKey, positions_prefix: StorageKey) -> Self {
+        Self {
+            keys: LookupMap::new(keys_prefix),
+            positions: LookupMap::new(positions_prefix),
+            start: 0,
+            end: 0,
+            len: 0,
+        }
+    }
+
+    /// Returns the number of indexed keys.
+    pub(crate) fn len(&self) -> u32 {
+        self.len
+    }
+
+    /// Returns the position of the first indexed key, or `end` if the index is empty.
+    pub(crate) fn start(&self) -> u32 {
+        self.start
+    }
+
+    /// Returns the position the next indexed key will get.
+    pub(crate) fn end(&self) -> u32 {
+        self.end
+    }
+
+    /// Returns the key at the position, `None` if it's a gap.
+    pub(crate) fn get(&self, position: u32) -> Option<&T> {
+        self.keys.get(&position)
+    }
+
+    /// Adds the key to the end of the index. Returns `false` if it's already indexed.
+    pub(crate) fn insert(&mut self, key: &T) -> bool {
+        if self.positions.contains_key(key) {
+            return false;
+        }
+
+        self.keys.insert(self.end, key.clone());
+        self.positions.insert(key.clone(), self.end);
+        self.end += 1;
+        self.len += 1;
+
+        true
+    }
+
+    pub(crate) fn remove(&mut self, key: &T) {
+        let Some(position) = self.positions.remove(key) else {
+            return;
+        };
+
+        self.keys.remove(&position);
+        self.len -= 1;
+
+        // Each position is passed once, so moving `start` costs a single read per removed key.
+        if position == self.start {
+            while self.start < self.end && !self.keys.contains_key(&self.start) {
+                self.start += 1;
+            }
+        }
+    }
+
+    /// Returns the keys at positions from `from` to `from + limit`.
+    pub(crate) fn page(&self, from: u32, limit: u32) -> Vec<T> {
+        (from.max(self.start)..from.saturating_add(limit).min(self.end))
+            .filter_map(|position| self.keys.get(&position).cloned())
+            .collect()
+    }
+
+    /// Returns the position the page following `page(from, limit)` starts at, if there are more keys.
+    /// Positions before the first indexed key are skipped.
+    pub(crate) fn next_page(&self, from: u32, limit: u32) -> Option<u32> {
+        let next = from.saturating_add(limit).max(self.start);
+
+        (next < self.end).then_some(next)
+    }
+}
@@ -1,18 +1,24 @@
-use claim_model::{Duration, UnixTimestamp};
-use near_sdk::env::{block_timestamp_ms, panic_str};
+use claim_model::{error::ClaimError, Duration, UnixTimestamp};
+use near_sdk::env::block_timestamp_ms;
 
 mod asserts;
+pub(crate) mod buckets;
+pub(crate) mod index;
 pub(crate) mod tests;
 
 fn ms_timestamp_to_seconds(ms: u64) -> UnixTimestamp {
-    u32::try_from(ms / 1000)
-        .unwrap_or_else(|err| panic_str(&format!("Failed to get convert milliseconds to Unix timestamp: {err}")))
+    u32::try_from(ms / 1000).unwrap_or_else(|_| ClaimError::InvalidTimestamp.panic())
 }
 
 pub(crate) fn now_seconds() -> UnixTimestamp {
     ms_timestamp_to_seconds(block_timestamp_ms())
 }
 
+/// Checks whether a lock taken at `locked_at` is still held. Expired locks count as released.
+pub(crate) fn is_lock_active(locked_at: Option<UnixTimestamp>, now: UnixTimestamp, expiry: Duration) -> bool {
+    locked_at.is_some_and(|locked_at| locked_at.is_within_period(now, expiry))
+}
+
 pub(crate) trait UnixTimestampExtension {
     fn is_within_period(&self, now: UnixTimestamp, period: Duration) -> bool;
 }
@@ -32,7 +38,7 @@ fn convert_milliseconds_to_unix_timestamp_successfully() {
 }
 
 #[test]
-#[should_panic(expected = "Failed to get convert milliseconds to Unix timestamp")]
+#[should_panic(expected = "Failed to convert milliseconds to Unix timestamp")]
 fn convert_milliseconds_to_unix_timestamp_with_unsuccessfully() {
     let millis: u64 = u64::MAX;
     let _timestamp = ms_timestamp_to_seconds(millis);
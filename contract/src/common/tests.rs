@@ -3,7 +3,7 @@
 use std::time::Duration;
 
 use claim_model::api::InitApi;
-use near_sdk::{test_utils::VMContextBuilder, testing_env, AccountId};
+use near_sdk::{test_utils::VMContextBuilder, testing_env, AccountId, Balance, Gas};
 
 use crate::Contract;
 
@@ -46,6 +46,16 @@ impl Context {
         testing_env!(self.builder.build());
     }
 
+    pub(crate) fn set_prepaid_gas(&mut self, gas: Gas) {
+        self.builder.prepaid_gas(gas);
+        testing_env!(self.builder.build());
+    }
+
+    pub(crate) fn set_attached_deposit(&mut self, deposit: Balance) {
+        self.builder.attached_deposit(deposit);
+        testing_env!(self.builder.build());
+    }
+
     pub(crate) fn set_block_timestamp_in_seconds(&mut self, seconds: u64) {
         self.set_block_timestamp(Duration::from_secs(seconds));
     }
@@ -1,7 +1,13 @@
-use claim_model::{api::ConfigApi, Duration};
-use near_sdk::near_bindgen;
+use claim_model::{
+    api::ConfigApi,
+    error::ClaimError,
+    event::{emit, EventKind, HistoryDepthData, PeriodData},
+    gas_config::GasConfig,
+    Duration,
+};
+use near_sdk::{near_bindgen, require};
 
-use crate::{Contract, ContractExt};
+use crate::{Contract, ContractExt, MIN_LOCK_EXPIRY};
 
 #[near_bindgen]
 impl ConfigApi for Contract {
@@ -9,11 +15,45 @@ impl ConfigApi for Contract {
         self.assert_oracle();
 
         self.claim_period = period;
+
+        emit(EventKind::ClaimPeriodChanged(PeriodData { period }));
     }
 
     fn set_burn_period(&mut self, period: Duration) {
         self.assert_oracle();
 
         self.burn_period = period;
+
+        emit(EventKind::BurnPeriodChanged(PeriodData { period }));
+    }
+
+    fn set_history_depth(&mut self, depth: u32) {
+        self.assert_oracle();
+
+        self.history_depth = depth;
+
+        emit(EventKind::HistoryDepthChanged(HistoryDepthData { depth }));
+    }
+
+    fn set_lock_expiry(&mut self, expiry: Duration) {
+        Self::assert_private();
+        require!(expiry >= MIN_LOCK_EXPIRY, ClaimError::InvalidConfig.to_string());
+
+        self.lock_expiry = expiry;
+
+        emit(EventKind::LockExpiryChanged(PeriodData { period: expiry }));
+    }
+
+    fn set_gas_config(&mut self, gas_config: GasConfig) {
+        Self::assert_private();
+        require!(gas_config.is_valid(), ClaimError::InvalidConfig.to_string());
+
+        self.gas_config = gas_config;
+
+        emit(EventKind::GasConfigChanged(gas_config));
+    }
+
+    fn get_gas_config(&self) -> GasConfig {
+        self.gas_config
     }
 }
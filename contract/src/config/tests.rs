@@ -1,8 +1,9 @@
 #![cfg(test)]
 
-use claim_model::api::ConfigApi;
+use claim_model::{api::ConfigApi, gas_config::GasConfig};
+use near_sdk::{test_utils::get_logs, Gas};
 
-use crate::common::tests::Context;
+use crate::{common::tests::Context, MIN_LOCK_EXPIRY};
 
 #[test]
 fn set_claim_period_by_oracle() {
@@ -57,3 +58,87 @@ fn set_burn_period_by_not_oracle() {
     context.switch_account(&accounts.alice);
     contract.set_burn_period(burn_period);
 }
+
+#[test]
+fn set_lock_expiry_by_contract_owner() {
+    let (mut context, mut contract, accounts) = Context::init_with_oracle();
+
+    context.switch_account(&accounts.owner);
+    contract.set_lock_expiry(MIN_LOCK_EXPIRY);
+
+    assert_eq!(MIN_LOCK_EXPIRY, contract.lock_expiry);
+
+    let logs = get_logs();
+    assert!(logs.last().unwrap().contains(r#""event":"lock_expiry_changed""#));
+    assert!(logs.last().unwrap().contains(&format!(r#""period":{MIN_LOCK_EXPIRY}"#)));
+}
+
+#[test]
+#[should_panic(expected = "Method is private")]
+fn set_lock_expiry_by_oracle() {
+    let (mut context, mut contract, accounts) = Context::init_with_oracle();
+
+    context.switch_account(&accounts.oracle);
+    contract.set_lock_expiry(MIN_LOCK_EXPIRY);
+}
+
+#[test]
+#[should_panic(expected = "Configuration value is out of range")]
+fn set_too_short_lock_expiry() {
+    let (mut context, mut contract, accounts) = Context::init_with_oracle();
+
+    context.switch_account(&accounts.owner);
+    contract.set_lock_expiry(MIN_LOCK_EXPIRY - 1);
+}
+
+#[test]
+fn set_gas_config_by_contract_owner() {
+    let (mut context, mut contract, accounts) = Context::init_with_oracle();
+    assert_eq!(GasConfig::default(), contract.get_gas_config());
+
+    let gas_config = GasConfig {
+        transfer: Gas(20 * Gas::ONE_TERA.0),
+        ..GasConfig::default()
+    };
+    context.switch_account(&accounts.owner);
+    contract.set_gas_config(gas_config);
+
+    assert_eq!(gas_config, contract.get_gas_config());
+
+    let logs = get_logs();
+    assert!(logs.last().unwrap().contains(r#""event":"gas_config_changed""#));
+    assert!(logs.last().unwrap().contains(r#""transfer":"20000000000000""#));
+}
+
+#[test]
+#[should_panic(expected = "Method is private")]
+fn set_gas_config_by_oracle() {
+    let (mut context, mut contract, accounts) = Context::init_with_oracle();
+
+    context.switch_account(&accounts.oracle);
+    contract.set_gas_config(GasConfig::default());
+}
+
+#[test]
+#[should_panic(expected = "Configuration value is out of range")]
+fn set_too_small_gas_config() {
+    let (mut context, mut contract, accounts) = Context::init_with_oracle();
+
+    context.switch_account(&accounts.owner);
+    contract.set_gas_config(GasConfig {
+        transfer_callback: Gas(Gas::ONE_TERA.0),
+        ..GasConfig::default()
+    });
+}
+
+#[test]
+#[should_panic(expected = "Configuration value is out of range")]
+fn set_gas_config_exceeding_prepaid_gas() {
+    let (mut context, mut contract, accounts) = Context::init_with_oracle();
+
+    context.switch_account(&accounts.owner);
+    contract.set_gas_config(GasConfig {
+        storage_registration: Gas(130 * Gas::ONE_TERA.0),
+        ..GasConfig::default()
+    });
+}
@@ -0,0 +1,62 @@
+use claim_model::{
+    account_history::{AccountHistory, HistoryEntry, HistoryEntryKind},
+    api::HistoryApi,
+    HistoryEntryView, TokensAmount, UnixTimestamp,
+};
+use near_sdk::{json_types::U128, near_bindgen, AccountId};
+
+use crate::{Contract, ContractExt};
+
+#[near_bindgen]
+impl HistoryApi for Contract {
+    fn get_account_history(&self, account_id: AccountId, from: u32, limit: u32) -> Vec<HistoryEntryView> {
+        let Some(history) = self.history.get(&account_id) else {
+            return vec![];
+        };
+
+        let first_index = history.first_index();
+
+        history
+            .entries
+            .iter()
+            .zip(first_index..)
+            .skip(from.saturating_sub(first_index) as usize)
+            .take(limit as usize)
+            .map(|(entry, index)| HistoryEntryView {
+                index,
+                timestamp: entry.timestamp,
+                kind: entry.kind,
+                amount: U128(entry.amount),
+            })
+            .collect()
+    }
+}
+
+impl Contract {
+    pub(crate) fn push_history(
+        &mut self,
+        account_id: &AccountId,
+        kind: HistoryEntryKind,
+        amount: TokensAmount,
+        now: UnixTimestamp,
+    ) {
+        if self.history_depth == 0 {
+            return;
+        }
+
+        let entry = HistoryEntry {
+            timestamp: now,
+            kind,
+            amount,
+        };
+
+        if let Some(history) = self.history.get_mut(account_id) {
+            history.push(entry, self.history_depth);
+        } else {
+            let mut history = AccountHistory::default();
+            history.push(entry, self.history_depth);
+
+            self.history.insert(account_id.clone(), history);
+        }
+    }
+}
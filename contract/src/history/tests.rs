@@ -0,0 +1,144 @@
+#![cfg(test)]
+
+use claim_model::{
+    account_history::HistoryEntryKind,
+    api::{ClaimApi, CleanApi, ConfigApi, HistoryApi, RecordApi},
+};
+use near_sdk::{json_types::U128, test_utils::get_logs};
+
+use crate::{
+    claim::api::test::EXT_TRANSFER_FUTURE,
+    common::tests::{data::set_test_future_success, Context},
+};
+
+#[test]
+fn history_contains_records_and_claims() {
+    let (mut context, mut contract, accounts) = Context::init_with_oracle();
+    set_test_future_success(EXT_TRANSFER_FUTURE, true);
+
+    context.switch_account(&accounts.oracle);
+    contract.set_history_depth(10);
+    contract.record_batch_for_hold(vec![(accounts.alice.clone(), U128(100_000))]);
+
+    context.set_block_timestamp_in_seconds(1_000);
+    contract.record_batch_for_hold(vec![(accounts.alice.clone(), U128(200_000))]);
+
+    let claim_timestamp = contract.claim_period + 100;
+    context.set_block_timestamp_in_seconds(claim_timestamp as u64);
+    context.switch_account(&accounts.alice);
+    contract.claim();
+
+    let history = contract.get_account_history(accounts.alice.clone(), 0, 10);
+    let entries: Vec<_> = history
+        .iter()
+        .map(|entry| (entry.index, entry.timestamp, entry.kind, entry.amount.0))
+        .collect();
+
+    assert_eq!(
+        vec![
+            (0, 0, HistoryEntryKind::Record, 100_000),
+            (1, 1_000, HistoryEntryKind::Record, 200_000),
+            (2, claim_timestamp, HistoryEntryKind::Claim, 300_000),
+        ],
+        entries
+    );
+
+    assert!(contract.get_account_history(accounts.bob, 0, 10).is_empty());
+}
+
+#[test]
+fn history_is_paginated() {
+    let (mut context, mut contract, accounts) = Context::init_with_oracle();
+
+    context.switch_account(&accounts.oracle);
+    contract.set_history_depth(10);
+    for amount in 1..=5 {
+        contract.record_batch_for_hold(vec![(accounts.alice.clone(), U128(amount))]);
+    }
+
+    let page: Vec<_> = contract
+        .get_account_history(accounts.alice.clone(), 1, 2)
+        .iter()
+        .map(|entry| (entry.index, entry.amount.0))
+        .collect();
+    assert_eq!(vec![(1, 2), (2, 3)], page);
+
+    let page = contract.get_account_history(accounts.alice.clone(), 5, 10);
+    assert!(page.is_empty());
+}
+
+#[test]
+fn history_is_limited_by_depth() {
+    let (mut context, mut contract, accounts) = Context::init_with_oracle();
+
+    context.switch_account(&accounts.oracle);
+    contract.set_history_depth(3);
+
+    for amount in 1..=5 {
+        contract.record_batch_for_hold(vec![(accounts.alice.clone(), U128(amount))]);
+    }
+
+    let page: Vec<_> = contract
+        .get_account_history(accounts.alice.clone(), 0, 10)
+        .iter()
+        .map(|entry| (entry.index, entry.amount.0))
+        .collect();
+    assert_eq!(vec![(2, 3), (3, 4), (4, 5)], page);
+}
+
+#[test]
+fn history_is_not_recorded_by_default() {
+    let (mut context, mut contract, accounts) = Context::init_with_oracle();
+
+    context.switch_account(&accounts.oracle);
+    contract.record_batch_for_hold(vec![(accounts.alice.clone(), U128(100_000))]);
+
+    assert!(contract.get_account_history(accounts.alice, 0, 10).is_empty());
+}
+
+#[test]
+fn history_contains_expirations_and_is_deleted_on_clean() {
+    let (mut context, mut contract, accounts) = Context::init_with_oracle();
+
+    context.switch_account(&accounts.oracle);
+    contract.set_history_depth(10);
+    contract.record_batch_for_hold(vec![(accounts.alice.clone(), U128(100_000))]);
+
+    context.set_block_timestamp_in_seconds(contract.burn_period as u64 + 100);
+    contract.record_batch_for_hold(vec![(accounts.alice.clone(), U128(50_000))]);
+
+    let entry = contract
+        .get_account_history(accounts.alice.clone(), 1, 1)
+        .pop()
+        .unwrap();
+    assert_eq!(HistoryEntryKind::Expire, entry.kind);
+    assert_eq!(100_000, entry.amount.0);
+
+    contract.clean(vec![accounts.alice.clone()]);
+
+    assert!(contract.get_account_history(accounts.alice.clone(), 0, 10).is_empty());
+    assert!(!contract.history.contains_key(&accounts.alice));
+}
+
+#[test]
+fn set_history_depth_by_oracle() {
+    let (mut context, mut contract, accounts) = Context::init_with_oracle();
+
+    context.switch_account(&accounts.oracle);
+    contract.set_history_depth(5);
+
+    assert_eq!(5, contract.history_depth);
+
+    let logs = get_logs();
+    assert!(logs.last().unwrap().contains(r#""event":"history_depth_changed""#));
+    assert!(logs.last().unwrap().contains(r#""depth":5"#));
+}
+
+#[test]
+#[should_panic(expected = "Unauthorized access")]
+fn set_history_depth_by_not_oracle() {
+    let (mut context, mut contract, accounts) = Context::init_with_oracle();
+
+    context.switch_account(&accounts.alice);
+    contract.set_history_depth(5);
+}
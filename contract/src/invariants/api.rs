@@ -0,0 +1,127 @@
+use claim_model::{
+    api::InvariantApi, BucketTotalMismatch, DanglingAccrual, InvariantCursor, InvariantReport, TokensAmount,
+};
+use near_sdk::{json_types::U128, near_bindgen};
+
+use crate::{Contract, ContractExt};
+
+#[near_bindgen]
+impl InvariantApi for Contract {
+    fn check_invariants(&self, cursor: Option<InvariantCursor>, limit: u32) -> InvariantReport {
+        let cursor = cursor.unwrap_or_default();
+
+        let mut bucket_total_mismatches = vec![];
+        let mut buckets_total: TokensAmount = cursor.buckets_total.0;
+        let mut buckets_checked = 0;
+
+        // The position, the first unsummed entry and the partial sum of the bucket to continue from.
+        // If that bucket has been burnt since the previous page, the check continues from the first bucket left.
+        let mut next_bucket = Some(if cursor.bucket_index < self.bucket_index.start() {
+            (self.bucket_index.start(), 0, 0)
+        } else {
+            (cursor.bucket_index, cursor.entry_index, cursor.bucket_partial_total.0)
+        });
+        let mut entries_left = limit;
+        let mut positions_left = limit;
+
+        while positions_left > 0 && entries_left > 0 {
+            let Some((position, from_entry, partial_total)) = next_bucket else {
+                break;
+            };
+            positions_left -= 1;
+
+            let following = self.bucket_index.next_page(position, 1).map(|next| (next, 0, 0));
+
+            let Some(((burn_period, timestamp), (accruals, stored_total))) = self
+                .bucket_index
+                .get(position)
+                .and_then(|key| self.accruals.get(*key).map(|bucket| (*key, bucket)))
+            else {
+                next_bucket = following;
+                continue;
+            };
+
+            let to_entry = from_entry.saturating_add(entries_left).min(accruals.len());
+            let partial_total = partial_total
+                + (from_entry..to_entry)
+                    .filter_map(|index| accruals.get(index))
+                    .sum::<TokensAmount>();
+            entries_left -= to_entry.saturating_sub(from_entry);
+
+            if to_entry < accruals.len() {
+                next_bucket = Some((position, to_entry, partial_total));
+                break;
+            }
+
+            if partial_total != *stored_total {
+                bucket_total_mismatches.push(BucketTotalMismatch {
+                    burn_period,
+                    timestamp,
+                    stored_total: U128(*stored_total),
+                    actual_total: U128(partial_total),
+                });
+            }
+
+            buckets_total += stored_total;
+            buckets_checked += 1;
+            next_bucket = following;
+        }
+
+        let mut dangling_accruals = vec![];
+        let mut accounts_checked = 0;
+
+        for account_id in self.account_index.page(cursor.account_index, limit) {
+            accounts_checked += 1;
+
+            let Some(account) = self.account(&account_id) else {
+                continue;
+            };
+            let burn_override = self.periods(&account_id).burn_period;
+
+            // References to removed buckets are expected, they are left behind by `burn`.
+            for (timestamp, index) in &account.accruals {
+                let Some((accruals, _)) = self.accruals.get((burn_override, *timestamp)) else {
+                    continue;
+                };
+
+                if *index >= accruals.len() {
+                    dangling_accruals.push(DanglingAccrual {
+                        account_id: account_id.clone(),
+                        timestamp: *timestamp,
+                        index: *index,
+                        bucket_len: accruals.len(),
+                    });
+                }
+            }
+        }
+
+        let is_held_balance_consistent = next_bucket
+            .is_none()
+            .then(|| buckets_total + self.pending_burn == self.stats.total_held);
+
+        let next_account = self.account_index.next_page(cursor.account_index, limit);
+        let next_cursor = (next_bucket.is_some() || next_account.is_some()).then(|| {
+            let (bucket_index, entry_index, partial_total) = next_bucket.unwrap_or((self.bucket_index.end(), 0, 0));
+
+            InvariantCursor {
+                bucket_index,
+                entry_index,
+                bucket_partial_total: U128(partial_total),
+                buckets_total: U128(buckets_total),
+                account_index: next_account.unwrap_or_else(|| self.account_index.end()),
+            }
+        });
+
+        InvariantReport {
+            buckets_checked,
+            accounts_checked,
+            bucket_total_mismatches,
+            dangling_accruals,
+            buckets_total: U128(buckets_total),
+            total_held: U128(self.stats.total_held),
+            pending_burn: U128(self.pending_burn),
+            is_held_balance_consistent,
+            next_cursor,
+        }
+    }
+}
@@ -0,0 +1,154 @@
+#![cfg(test)]
+
+use claim_model::{
+    api::{BurnApi, ClaimApi, InvariantApi, RecordApi},
+    BucketTotalMismatch, DanglingAccrual, InvariantCursor,
+};
+use near_sdk::json_types::U128;
+
+use crate::{
+    burn::api::test::EXT_BURN_FUTURE,
+    claim::api::test::EXT_TRANSFER_FUTURE,
+    common::tests::{data::set_test_future_success, Context},
+};
+
+#[test]
+fn check_invariants_of_consistent_state() {
+    let (mut context, mut contract, accounts) = Context::init_with_oracle();
+    set_test_future_success(EXT_TRANSFER_FUTURE, false);
+
+    context.switch_account(&accounts.oracle);
+    contract.record_batch_for_hold(vec![
+        (accounts.alice.clone(), U128(100_000)),
+        (accounts.bob.clone(), U128(200_000)),
+    ]);
+    context.set_block_timestamp_in_seconds(10);
+    contract.record_batch_for_hold(vec![(accounts.alice.clone(), U128(300_000))]);
+
+    // A failed claim moves the returned tokens to the end of their buckets.
+    context.set_block_timestamp_in_seconds(contract.claim_period as u64 + 100);
+    context.switch_account(&accounts.alice);
+    contract.claim();
+
+    let report = contract.check_invariants(None, 10);
+
+    assert!(report.is_ok());
+    assert_eq!(2, report.buckets_checked);
+    assert_eq!(2, report.accounts_checked);
+    assert_eq!(600_000, report.buckets_total.0);
+    assert_eq!(Some(true), report.is_held_balance_consistent);
+    assert_eq!(None, report.next_cursor);
+}
+
+#[test]
+fn check_invariants_with_pagination() {
+    let (mut context, mut contract, accounts) = Context::init_with_oracle();
+
+    context.switch_account(&accounts.oracle);
+    contract.record_batch_for_hold(vec![(accounts.alice.clone(), U128(100_000))]);
+    context.set_block_timestamp_in_seconds(10);
+    contract.record_batch_for_hold(vec![(accounts.bob.clone(), U128(200_000))]);
+
+    let first_page = contract.check_invariants(None, 1);
+    assert!(first_page.is_ok());
+    assert_eq!(1, first_page.buckets_checked);
+    assert_eq!(None, first_page.is_held_balance_consistent);
+
+    let second_page = contract.check_invariants(first_page.next_cursor, 1);
+    assert_eq!(1, second_page.buckets_checked);
+    assert_eq!(None, second_page.next_cursor);
+    assert_eq!(300_000, second_page.buckets_total.0);
+    assert_eq!(Some(true), second_page.is_held_balance_consistent);
+}
+
+#[test]
+fn check_invariants_of_bucket_larger_than_page() {
+    let (mut context, mut contract, accounts) = Context::init_with_oracle();
+
+    context.switch_account(&accounts.oracle);
+    contract.record_batch_for_hold(vec![
+        (accounts.alice.clone(), U128(100_000)),
+        (accounts.bob.clone(), U128(200_000)),
+    ]);
+    contract.record_batch_for_hold(vec![(accounts.alice.clone(), U128(300_000))]);
+
+    let first_page = contract.check_invariants(None, 2);
+    assert_eq!(0, first_page.buckets_checked);
+    assert_eq!(2, first_page.accounts_checked);
+    assert_eq!(
+        Some(InvariantCursor {
+            bucket_index: 0,
+            entry_index: 2,
+            bucket_partial_total: U128(300_000),
+            buckets_total: U128(0),
+            account_index: 2,
+        }),
+        first_page.next_cursor
+    );
+
+    let second_page = contract.check_invariants(first_page.next_cursor, 2);
+    assert!(second_page.is_ok());
+    assert_eq!(1, second_page.buckets_checked);
+    assert_eq!(0, second_page.accounts_checked);
+    assert_eq!(Some(true), second_page.is_held_balance_consistent);
+    assert_eq!(None, second_page.next_cursor);
+}
+
+#[test]
+fn check_invariants_skips_burnt_buckets() {
+    let (mut context, mut contract, accounts) = Context::init_with_oracle();
+    set_test_future_success(EXT_BURN_FUTURE, true);
+
+    context.switch_account(&accounts.oracle);
+    contract.record_batch_for_hold(vec![(accounts.alice.clone(), U128(100_000))]);
+    context.set_block_timestamp_in_seconds(10);
+    contract.record_batch_for_hold(vec![(accounts.bob.clone(), U128(200_000))]);
+
+    context.set_block_timestamp_in_seconds(contract.burn_period as u64 + 5);
+    contract.burn();
+
+    let report = contract.check_invariants(None, 1);
+    assert!(report.is_ok());
+    assert_eq!(1, report.buckets_checked);
+    assert_eq!(200_000, report.buckets_total.0);
+    assert_eq!(Some(true), report.is_held_balance_consistent);
+}
+
+#[test]
+fn check_invariants_of_corrupted_state() {
+    let (mut context, mut contract, accounts) = Context::init_with_oracle();
+
+    context.switch_account(&accounts.oracle);
+    contract.record_batch_for_hold(vec![(accounts.alice.clone(), U128(100_000))]);
+
+    contract.accruals.get_mut((None, 0)).unwrap().1 = 150_000;
+    contract
+        .accounts
+        .get_mut(&accounts.alice)
+        .unwrap()
+        .accruals
+        .push((0, 5));
+
+    let report = contract.check_invariants(None, 10);
+
+    assert!(!report.is_ok());
+    assert_eq!(
+        vec![BucketTotalMismatch {
+            burn_period: None,
+            timestamp: 0,
+            stored_total: U128(150_000),
+            actual_total: U128(100_000),
+        }],
+        report.bucket_total_mismatches
+    );
+    assert_eq!(
+        vec![DanglingAccrual {
+            account_id: accounts.alice,
+            timestamp: 0,
+            index: 5,
+            bucket_len: 1,
+        }],
+        report.dangling_accruals
+    );
+    assert_eq!(Some(false), report.is_held_balance_consistent);
+}
@@ -1,21 +1,48 @@
-use claim_model::{account_record::AccountRecord, api::InitApi, Duration, TokensAmount, UnixTimestamp};
+use claim_model::{
+    account_history::AccountHistory,
+    account_record::{AccountPeriods, AccountRecord, LegacyAccountRecord},
+    api::InitApi,
+    failed_claim::FailedClaim,
+    gas_config::GasConfig,
+    oracle_quota::OracleQuotaRecord,
+    stats::Stats,
+    Duration, TokensAmount, UnixTimestamp,
+};
 use near_sdk::{
     borsh::{self, BorshDeserialize, BorshSerialize},
     near_bindgen,
-    store::{LookupMap, UnorderedMap, UnorderedSet, Vector},
-    AccountId, BorshStorageKey, PanicOnDefault,
+    store::{LookupMap, UnorderedMap, UnorderedSet},
+    AccountId, Balance, BorshStorageKey, PanicOnDefault,
+};
+
+use crate::common::{
+    buckets::{BucketKey, Buckets},
+    index::PositionIndex,
 };
 
+mod account;
 mod auth;
 mod burn;
 mod claim;
 mod clean;
 mod common;
 mod config;
+mod history;
+mod invariants;
+mod lock;
+mod migration;
+mod quota;
 mod record;
+mod stats;
+mod storage;
 
 const INITIAL_CLAIM_PERIOD_MS: u32 = 24 * 60 * 60;
 const INITIAL_BURN_PERIOD_MS: u32 = 30 * 24 * 60 * 60;
+const INITIAL_HISTORY_DEPTH: u32 = 0;
+const INITIAL_LOCK_EXPIRY: Duration = 60 * 60;
+const MIN_LOCK_EXPIRY: Duration = 10 * 60;
+/// Keeps timestamps derived from the account periods within `UnixTimestamp`.
+const MAX_ACCOUNT_PERIOD: Duration = 10 * 365 * 24 * 60 * 60;
 
 /// The main structure representing a smart contract for managing fungible tokens.
 #[near_bindgen]
@@ -33,6 +60,12 @@ pub struct Contract {
     /// functions such as burning tokens. These accounts are trusted and have elevated privileges.
     oracles: UnorderedSet<AccountId>,
 
+    /// A map containing recording limits and their current usage for each limited oracle.
+    ///
+    /// `oracle_quotas` restricts the total amount and the number of entries an oracle can
+    /// record within a time window. Oracles without an entry here are not limited.
+    oracle_quotas: LookupMap<AccountId, OracleQuotaRecord>,
+
     /// The period in seconds during which tokens are locked after being claimed.
     ///
     /// `claim_period` defines the duration for which the tokens remain locked and
@@ -52,6 +85,7 @@ pub struct Contract {
     /// entries in the `accounts` field. It is essential for tracking token accruals over time.
     /// `AccountRecord` entries in `accounts` map contain pairs of a timestamp pointing to exact
     /// entry in `accruals` and index of particular accrual in corresponding vector.
+    /// Accruals of accounts with a burn period override are kept in separate buckets of that period.
     ///
     /// Here is an illustration of the connection:
     /// ```text
@@ -65,7 +99,19 @@ pub struct Contract {
     ///  │     [(1705066501, 2)]        │
     ///  └────────────┘      └──────────┘
     /// ```
-    accruals: UnorderedMap<UnixTimestamp, (Vector<TokensAmount>, TokensAmount)>,
+    accruals: Buckets,
+
+    /// An index of keys of all buckets in `accruals` in the order they were created.
+    ///
+    /// `bucket_index` allows to check the buckets in pages without skipping over the keys of `accruals`.
+    bucket_index: PositionIndex<BucketKey>,
+
+    /// The amount of tokens which are no longer assigned to any account but still held by the contract.
+    ///
+    /// `pending_burn` collects tokens whose accrual entry has been removed from `accruals` by
+    /// a concurrent operation, e.g. tokens returned by a failed claim transfer after `burn` has
+    /// removed their entry. They are burnt along with expired accruals by the next `burn`.
+    pending_burn: TokensAmount,
 
     /// A map containing accrual and service details for each user account.
     ///
@@ -74,12 +120,84 @@ pub struct Contract {
     /// comprehensive view of each user's token status.
     accounts: LookupMap<AccountId, AccountRecord>,
 
-    /// Indicates whether a service call is currently in progress.
+    /// A map containing records of accounts stored by the version before `AccountRecord.locked_at`.
+    ///
+    /// `legacy_accounts` keeps the storage prefix of the previous version. Its records are moved
+    /// to `accounts` when the account is indexed or its balance is changed, and read in place
+    /// by views until then.
+    legacy_accounts: LookupMap<AccountId, LegacyAccountRecord>,
+
+    /// A map containing the claim and burn periods of accounts which override the contract-wide ones.
+    ///
+    /// `account_periods` has entries only for accounts with an override set by `set_account_periods`.
+    account_periods: LookupMap<AccountId, AccountPeriods>,
+
+    /// An index of IDs of all accounts registered in `accounts`.
+    ///
+    /// `account_index` allows to list all accounts the contract holds tokens for in pages
+    /// which stay the same when other accounts are added or removed.
+    account_index: PositionIndex<AccountId>,
+
+    /// A map containing the latest failed claim of each account whose last claim has failed.
+    ///
+    /// `failed_claims` lets users see why their claim hasn't arrived and retry it with
+    /// `retry_claim`. An entry is removed once a claim of the account succeeds.
+    failed_claims: LookupMap<AccountId, FailedClaim>,
+
+    /// A map containing a bounded activity log for each user account.
+    ///
+    /// `history` keeps the most recent records, claims and expirations affecting an account's balance,
+    /// so users can see when and how much they have claimed. It is deleted along with the account.
+    history: LookupMap<AccountId, AccountHistory>,
+
+    /// The maximum number of entries kept in each account's activity log.
     ///
-    /// `is_service_call_running` is used to prevent double spending by indicating if the
-    /// contract is currently executing a service call. This flag ensures the integrity of
+    /// `history_depth` keeps storage usage of `history` predictable. Zero, the initial value,
+    /// disables recording of the activity logs.
+    history_depth: u32,
+
+    /// Contract-wide counters maintained by the operations affecting balances.
+    ///
+    /// `stats` allows to retrieve aggregated figures such as the total held amount and lifetime
+    /// totals of recorded, claimed, burnt and revoked tokens without iterating over the state.
+    stats: Stats,
+
+    /// The period in seconds after which locks of accounts are released.
+    ///
+    /// `lock_expiry` guarantees that an account doesn't stay locked for good if a callback
+    /// of its claim is never executed. The service call lock is released by `reset_service_call` only.
+    lock_expiry: Duration,
+
+    /// Gas attached to cross-contract calls to the token contract and to their callbacks.
+    ///
+    /// `gas_config` allows to adjust the gas without a contract upgrade, e.g. when the token
+    /// contract becomes more expensive after its own upgrade.
+    gas_config: GasConfig,
+
+    /// The amount of yoctoNEAR available for storage registrations of claim receivers.
+    ///
+    /// `storage_pool` is funded by the owner and pays for `storage_deposit` on the token contract
+    /// for accounts which aren't registered there, so their claims don't fail.
+    storage_pool: Balance,
+
+    /// The maximum yoctoNEAR paid for the storage registration of a single account.
+    ///
+    /// `storage_deposit_cap` limits the total paid for each account across all of its registrations.
+    /// It disables storage registration if it's `None`.
+    storage_deposit_cap: Option<Balance>,
+
+    /// A map containing the yoctoNEAR paid from `storage_pool` for storage registrations of each account.
+    ///
+    /// `storage_subsidies` is kept when the account is removed, so an account which unregisters
+    /// from the token contract can't get the pool to pay for it again and again.
+    storage_subsidies: LookupMap<AccountId, Balance>,
+
+    /// The timestamp when the service call currently in progress has started, if any.
+    ///
+    /// `service_call_started_at` is used to prevent double spending by indicating if the
+    /// contract is currently executing a service call. This ensures the integrity of
     /// token transactions and operations within the contract.
-    is_service_call_running: bool,
+    service_call_started_at: Option<UnixTimestamp>,
 }
 
 #[derive(BorshStorageKey, BorshSerialize)]
@@ -88,6 +206,18 @@ enum StorageKey {
     Accruals,
     AccrualsEntry(u32),
     Oracles,
+    OracleQuotas,
+    History,
+    AccountIds,
+    FailedClaims,
+    AccountPeriods,
+    AccountPositions,
+    AccountRecords,
+    BucketTimestamps,
+    BucketPositions,
+    OverriddenAccruals,
+    OverriddenAccrualsEntry(Duration, UnixTimestamp),
+    StorageSubsidies,
 }
 
 #[near_bindgen]
@@ -99,14 +229,37 @@ impl InitApi for Contract {
         Self {
             token_account_id,
 
-            accounts: LookupMap::new(StorageKey::Accounts),
-            accruals: UnorderedMap::new(StorageKey::Accruals),
+            accounts: LookupMap::new(StorageKey::AccountRecords),
+            legacy_accounts: LookupMap::new(StorageKey::Accounts),
+            account_periods: LookupMap::new(StorageKey::AccountPeriods),
+            account_index: PositionIndex::new(StorageKey::AccountIds, StorageKey::AccountPositions),
+            accruals: Buckets::new(UnorderedMap::new(StorageKey::Accruals), StorageKey::OverriddenAccruals),
+            bucket_index: PositionIndex::new(StorageKey::BucketTimestamps, StorageKey::BucketPositions),
+            pending_burn: 0,
             oracles: UnorderedSet::new(StorageKey::Oracles),
+            oracle_quotas: LookupMap::new(StorageKey::OracleQuotas),
+            history: LookupMap::new(StorageKey::History),
+            failed_claims: LookupMap::new(StorageKey::FailedClaims),
 
             claim_period: INITIAL_CLAIM_PERIOD_MS,
             burn_period: INITIAL_BURN_PERIOD_MS,
+            history_depth: INITIAL_HISTORY_DEPTH,
+            lock_expiry: INITIAL_LOCK_EXPIRY,
+            gas_config: GasConfig::default(),
+            storage_pool: 0,
+            storage_deposit_cap: None,
+            storage_subsidies: LookupMap::new(StorageKey::StorageSubsidies),
+
+            stats: Stats::default(),
 
-            is_service_call_running: false,
+            service_call_started_at: None,
         }
     }
+
+    #[init(ignore_state)]
+    fn migrate(account_ids: Vec<AccountId>) -> Self {
+        Self::assert_private();
+
+        Self::migrate_from_legacy_state(account_ids)
+    }
 }
@@ -0,0 +1,32 @@
+use claim_model::{
+    api::LockApi,
+    error::ClaimError,
+    event::{emit, EventKind, ServiceCallResetData, UnlockData},
+};
+use near_sdk::{near_bindgen, AccountId};
+
+use crate::{Contract, ContractExt};
+
+#[near_bindgen]
+impl LockApi for Contract {
+    fn force_unlock(&mut self, account_id: AccountId) {
+        Self::assert_private();
+        self.upgrade_account(&account_id);
+
+        let account_data = self
+            .accounts
+            .get_mut(&account_id)
+            .unwrap_or_else(|| ClaimError::AccountNotFound.panic());
+        let locked_at = account_data.locked_at.take();
+
+        emit(EventKind::AccountUnlocked(UnlockData { account_id, locked_at }));
+    }
+
+    fn reset_service_call(&mut self) {
+        Self::assert_private();
+
+        let started_at = self.service_call_started_at.take();
+
+        emit(EventKind::ServiceCallReset(ServiceCallResetData { started_at }));
+    }
+}
@@ -0,0 +1,123 @@
+#![cfg(test)]
+
+use claim_model::api::{BurnApi, ClaimApi, ConfigApi, LockApi, RecordApi};
+use near_sdk::{json_types::U128, test_utils::get_logs, PromiseOrValue};
+
+use crate::{
+    burn::api::test::EXT_BURN_FUTURE,
+    claim::api::test::EXT_TRANSFER_FUTURE,
+    common::tests::{data::set_test_future_success, Context},
+    MIN_LOCK_EXPIRY,
+};
+
+#[test]
+#[should_panic(expected = "Another operation is running")]
+fn claim_with_active_lock() {
+    let (mut context, mut contract, accounts) = Context::init_with_oracle();
+
+    context.switch_account(&accounts.oracle);
+    contract.record_batch_for_hold(vec![(accounts.alice.clone(), U128(100_000))]);
+
+    let now = contract.claim_period + 1;
+    context.set_block_timestamp_in_seconds(now as u64);
+    contract.accounts.get_mut(&accounts.alice).unwrap().locked_at = Some(now);
+
+    context.switch_account(&accounts.alice);
+    contract.claim();
+}
+
+#[test]
+fn claim_with_expired_lock() {
+    let (mut context, mut contract, accounts) = Context::init_with_oracle();
+    set_test_future_success(EXT_TRANSFER_FUTURE, true);
+
+    context.switch_account(&accounts.oracle);
+    contract.record_batch_for_hold(vec![(accounts.alice.clone(), U128(100_000))]);
+
+    context.switch_account(&accounts.owner);
+    contract.set_lock_expiry(MIN_LOCK_EXPIRY);
+
+    contract.accounts.get_mut(&accounts.alice).unwrap().locked_at = Some(0);
+
+    context.set_block_timestamp_in_seconds(contract.claim_period as u64 + 1);
+    assert!(!contract.get_account(accounts.alice.clone()).unwrap().is_locked);
+
+    context.switch_account(&accounts.alice);
+    let PromiseOrValue::Value(result) = contract.claim() else {
+        panic!("Expected value");
+    };
+
+    assert_eq!(100_000, result.total.0);
+    assert!(contract.accounts.get(&accounts.alice).unwrap().locked_at.is_none());
+}
+
+#[test]
+fn force_unlock_by_contract_owner() {
+    let (mut context, mut contract, accounts) = Context::init_with_oracle();
+
+    context.switch_account(&accounts.oracle);
+    contract.record_batch_for_hold(vec![(accounts.alice.clone(), U128(100_000))]);
+    contract.accounts.get_mut(&accounts.alice).unwrap().locked_at = Some(0);
+    assert!(contract.get_account(accounts.alice.clone()).unwrap().is_locked);
+
+    context.switch_account(&accounts.owner);
+    contract.force_unlock(accounts.alice.clone());
+
+    assert!(!contract.get_account(accounts.alice.clone()).unwrap().is_locked);
+
+    let logs = get_logs();
+    assert!(logs.last().unwrap().contains(r#""event":"account_unlocked""#));
+    assert!(logs.last().unwrap().contains(r#""locked_at":0"#));
+}
+
+#[test]
+#[should_panic(expected = "Method is private")]
+fn force_unlock_not_by_contract_owner() {
+    let (mut context, mut contract, accounts) = Context::init_with_oracle();
+
+    context.switch_account(&accounts.oracle);
+    contract.record_batch_for_hold(vec![(accounts.alice.clone(), U128(100_000))]);
+    contract.force_unlock(accounts.alice.clone());
+}
+
+#[test]
+#[should_panic(expected = "Another service call is running")]
+fn burn_with_active_service_call() {
+    let (mut context, mut contract, accounts) = Context::init_with_oracle();
+
+    contract.service_call_started_at = Some(0);
+
+    context.switch_account(&accounts.oracle);
+    contract.burn();
+}
+
+#[test]
+#[should_panic(expected = "Another service call is running")]
+fn burn_with_service_call_older_than_lock_expiry() {
+    let (mut context, mut contract, accounts) = Context::init_with_oracle();
+    set_test_future_success(EXT_BURN_FUTURE, true);
+
+    contract.service_call_started_at = Some(0);
+
+    context.switch_account(&accounts.oracle);
+    context.set_block_timestamp_in_seconds(contract.lock_expiry as u64);
+    contract.burn();
+}
+
+#[test]
+fn reset_service_call_by_contract_owner() {
+    let (mut context, mut contract, accounts) = Context::init_with_oracle();
+    set_test_future_success(EXT_BURN_FUTURE, true);
+
+    contract.service_call_started_at = Some(0);
+
+    context.switch_account(&accounts.owner);
+    contract.reset_service_call();
+
+    let logs = get_logs();
+    assert!(logs.last().unwrap().contains(r#""event":"service_call_reset""#));
+    assert!(logs.last().unwrap().contains(r#""started_at":0"#));
+
+    context.switch_account(&accounts.oracle);
+    contract.burn();
+}
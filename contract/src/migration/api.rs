@@ -0,0 +1,100 @@
+use claim_model::{
+    account_record::LegacyAccountRecord, error::ClaimError, gas_config::GasConfig, stats::Stats, Duration,
+    TokensAmount, UnixTimestamp,
+};
+use near_sdk::{
+    borsh::{self, BorshDeserialize, BorshSerialize},
+    env,
+    store::{LookupMap, UnorderedMap, UnorderedSet, Vector},
+    AccountId,
+};
+
+use crate::{
+    common::{
+        buckets::{BucketKey, Buckets},
+        index::PositionIndex,
+        now_seconds,
+    },
+    Contract, StorageKey, INITIAL_HISTORY_DEPTH, INITIAL_LOCK_EXPIRY,
+};
+
+/// The state layout of the contract before the account index and the contract-wide settings
+/// were introduced.
+#[derive(BorshDeserialize, BorshSerialize)]
+pub(crate) struct OldContract {
+    pub(crate) token_account_id: AccountId,
+    pub(crate) oracles: UnorderedSet<AccountId>,
+    pub(crate) claim_period: Duration,
+    pub(crate) burn_period: Duration,
+    pub(crate) accruals: UnorderedMap<UnixTimestamp, (Vector<TokensAmount>, TokensAmount)>,
+    pub(crate) accounts: LookupMap<AccountId, LegacyAccountRecord>,
+    pub(crate) is_service_call_running: bool,
+}
+
+impl Contract {
+    pub(crate) fn migrate_from_legacy_state(account_ids: Vec<AccountId>) -> Self {
+        let OldContract {
+            token_account_id,
+            oracles,
+            claim_period,
+            burn_period,
+            accruals,
+            accounts,
+            is_service_call_running,
+        } = env::state_read().unwrap_or_else(|| ClaimError::StateNotInitialized.panic());
+
+        let mut contract = Self {
+            token_account_id,
+
+            accounts: LookupMap::new(StorageKey::AccountRecords),
+            legacy_accounts: accounts,
+            account_periods: LookupMap::new(StorageKey::AccountPeriods),
+            account_index: PositionIndex::new(StorageKey::AccountIds, StorageKey::AccountPositions),
+            accruals: Buckets::new(accruals, StorageKey::OverriddenAccruals),
+            bucket_index: PositionIndex::new(StorageKey::BucketTimestamps, StorageKey::BucketPositions),
+            pending_burn: 0,
+            oracles,
+            oracle_quotas: LookupMap::new(StorageKey::OracleQuotas),
+            history: LookupMap::new(StorageKey::History),
+            failed_claims: LookupMap::new(StorageKey::FailedClaims),
+
+            claim_period,
+            burn_period,
+            history_depth: INITIAL_HISTORY_DEPTH,
+            lock_expiry: INITIAL_LOCK_EXPIRY,
+            gas_config: GasConfig::default(),
+            storage_pool: 0,
+            storage_deposit_cap: None,
+            storage_subsidies: LookupMap::new(StorageKey::StorageSubsidies),
+
+            stats: Stats::default(),
+
+            // A burn started by the previous version is still waiting for its callback.
+            service_call_started_at: is_service_call_running.then(now_seconds),
+        };
+
+        contract.backfill_stats();
+        contract.index_buckets();
+        contract.index_registered_accounts(account_ids);
+
+        contract
+    }
+
+    /// Calculates the held balance and the bucket bounds of the accruals stored
+    /// by the previous version. Lifetime totals start from zero.
+    fn backfill_stats(&mut self) {
+        self.stats.total_held = self.accruals.iter().map(|(_, (_, total))| total).sum();
+        self.stats.oldest_bucket_at = self.accruals.iter().map(|((_, timestamp), _)| timestamp).min();
+        self.stats.newest_bucket_at = self.accruals.iter().map(|((_, timestamp), _)| timestamp).max();
+    }
+
+    /// Adds the buckets stored by the previous version to the bucket index in the order they were created.
+    fn index_buckets(&mut self) {
+        let mut keys: Vec<BucketKey> = self.accruals.iter().map(|(key, _)| key).collect();
+        keys.sort_unstable_by_key(|(_, timestamp)| *timestamp);
+
+        for key in &keys {
+            self.bucket_index.insert(key);
+        }
+    }
+}
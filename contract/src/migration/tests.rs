@@ -0,0 +1,184 @@
+#![cfg(test)]
+
+use claim_model::{
+    account_record::LegacyAccountRecord,
+    api::{AccountApi, ClaimApi, InitApi, InvariantApi, LockApi, RecordApi},
+};
+use near_sdk::{
+    env,
+    json_types::U128,
+    store::{LookupMap, UnorderedMap, UnorderedSet, Vector},
+};
+
+use crate::{
+    common::tests::Context, migration::api::OldContract, Contract, StorageKey, INITIAL_HISTORY_DEPTH,
+    INITIAL_LOCK_EXPIRY,
+};
+
+#[test]
+fn migrate_legacy_state() {
+    let (_context, _, accounts) = Context::init();
+
+    let mut old_contract = OldContract {
+        token_account_id: accounts.token.clone(),
+        oracles: UnorderedSet::new(StorageKey::Oracles),
+        claim_period: 100,
+        burn_period: 1_000,
+        accruals: UnorderedMap::new(StorageKey::Accruals),
+        accounts: LookupMap::new(StorageKey::Accounts),
+        is_service_call_running: false,
+    };
+
+    old_contract.oracles.insert(accounts.oracle.clone());
+
+    let mut accruals = Vector::new(StorageKey::AccrualsEntry(0));
+    accruals.push(100_000);
+    accruals.flush();
+    old_contract.accruals.insert(0, (accruals, 100_000));
+
+    let mut accruals = Vector::new(StorageKey::AccrualsEntry(500));
+    accruals.push(200_000);
+    accruals.flush();
+    old_contract.accruals.insert(500, (accruals, 200_000));
+
+    old_contract.accounts.insert(
+        accounts.alice.clone(),
+        LegacyAccountRecord {
+            accruals: vec![(0, 0)],
+            is_enabled: true,
+            claim_period_refreshed_at: 0,
+            is_locked: false,
+        },
+    );
+
+    old_contract.oracles.flush();
+    old_contract.accruals.flush();
+    old_contract.accounts.flush();
+    env::state_write(&old_contract);
+
+    let contract = Contract::migrate(vec![accounts.alice.clone(), accounts.bob.clone()]);
+
+    assert_eq!(accounts.token, contract.token_account_id);
+    assert!(contract.oracles.contains(&accounts.oracle));
+    assert_eq!(100, contract.claim_period);
+    assert_eq!(1_000, contract.burn_period);
+    assert_eq!(INITIAL_HISTORY_DEPTH, contract.history_depth);
+    assert_eq!(INITIAL_LOCK_EXPIRY, contract.lock_expiry);
+    assert_eq!(None, contract.service_call_started_at);
+
+    assert_eq!(300_000, contract.stats.total_held);
+    assert_eq!(Some(0), contract.stats.oldest_bucket_at);
+    assert_eq!(Some(500), contract.stats.newest_bucket_at);
+    assert_eq!(2, contract.bucket_index.len());
+    assert_eq!(
+        Some(true),
+        contract.check_invariants(None, 10).is_held_balance_consistent
+    );
+
+    assert_eq!(vec![accounts.alice.clone()], contract.get_accounts(0, 10).account_ids);
+    assert!(contract.accounts.contains_key(&accounts.alice));
+    assert!(!contract.legacy_accounts.contains_key(&accounts.alice));
+    assert_eq!(100_000, contract.get_claimable_balance_for_account(accounts.alice).0);
+}
+
+#[test]
+fn upgrade_legacy_record_on_write() {
+    let (mut context, _, accounts) = Context::init();
+
+    let mut old_contract = OldContract {
+        token_account_id: accounts.token.clone(),
+        oracles: UnorderedSet::new(StorageKey::Oracles),
+        claim_period: 100,
+        burn_period: 1_000,
+        accruals: UnorderedMap::new(StorageKey::Accruals),
+        accounts: LookupMap::new(StorageKey::Accounts),
+        is_service_call_running: false,
+    };
+
+    old_contract.accounts.insert(
+        accounts.alice.clone(),
+        LegacyAccountRecord {
+            accruals: vec![],
+            is_enabled: true,
+            claim_period_refreshed_at: 0,
+            is_locked: true,
+        },
+    );
+
+    old_contract.oracles.flush();
+    old_contract.accruals.flush();
+    old_contract.accounts.flush();
+    env::state_write(&old_contract);
+
+    let mut contract = Contract::migrate(vec![]);
+
+    assert!(contract.get_account(accounts.alice.clone()).unwrap().is_locked);
+    assert!(!contract.accounts.contains_key(&accounts.alice));
+    assert_eq!(0, contract.get_accounts_count());
+
+    context.switch_account(&accounts.owner);
+    contract.force_unlock(accounts.alice.clone());
+
+    assert!(!contract.get_account(accounts.alice.clone()).unwrap().is_locked);
+    assert!(contract.accounts.contains_key(&accounts.alice));
+    assert!(!contract.legacy_accounts.contains_key(&accounts.alice));
+    assert_eq!(vec![accounts.alice.clone()], contract.get_accounts(0, 10).account_ids);
+}
+
+#[test]
+fn index_legacy_account_on_record() {
+    let (mut context, _, accounts) = Context::init();
+
+    let mut old_contract = OldContract {
+        token_account_id: accounts.token.clone(),
+        oracles: UnorderedSet::new(StorageKey::Oracles),
+        claim_period: 100,
+        burn_period: 1_000,
+        accruals: UnorderedMap::new(StorageKey::Accruals),
+        accounts: LookupMap::new(StorageKey::Accounts),
+        is_service_call_running: false,
+    };
+
+    old_contract.oracles.insert(accounts.oracle.clone());
+    old_contract.accounts.insert(
+        accounts.alice.clone(),
+        LegacyAccountRecord {
+            accruals: vec![],
+            is_enabled: true,
+            claim_period_refreshed_at: 0,
+            is_locked: false,
+        },
+    );
+
+    old_contract.oracles.flush();
+    old_contract.accruals.flush();
+    old_contract.accounts.flush();
+    env::state_write(&old_contract);
+
+    let mut contract = Contract::migrate(vec![]);
+    assert_eq!(0, contract.get_accounts_count());
+
+    context.switch_account(&accounts.oracle);
+    contract.record_batch_for_hold(vec![(accounts.alice.clone(), U128(100_000))]);
+
+    assert_eq!(1, contract.get_accounts_count());
+    assert_eq!(vec![accounts.alice.clone()], contract.get_accounts(0, 10).account_ids);
+    assert!(contract.check_invariants(None, 10).is_ok());
+}
+
+#[test]
+#[should_panic(expected = "Contract state is not initialized")]
+fn migrate_without_state() {
+    Context::init();
+
+    Contract::migrate(vec![]);
+}
+
+#[test]
+#[should_panic(expected = "Method is private")]
+fn migrate_not_by_contract_itself() {
+    let (mut context, _, accounts) = Context::init();
+
+    context.switch_account(&accounts.alice);
+    Contract::migrate(vec![]);
+}
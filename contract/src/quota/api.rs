@@ -0,0 +1,76 @@
+use claim_model::{
+    api::QuotaApi,
+    error::ClaimError,
+    event::{emit, EventKind, OracleQuotaData, QuotaExceededData},
+    oracle_quota::{OracleQuota, OracleQuotaRecord},
+    OracleQuotaView, TokensAmount, UnixTimestamp,
+};
+use near_sdk::{env::predecessor_account_id, json_types::U128, near_bindgen, require, AccountId};
+
+use crate::{common::now_seconds, Contract, ContractExt};
+
+#[near_bindgen]
+impl QuotaApi for Contract {
+    fn set_oracle_quota(&mut self, account_id: AccountId, quota: Option<OracleQuota>) {
+        Self::assert_private();
+
+        require!(self.oracles.contains(&account_id), ClaimError::NoSuchOracle.to_string());
+
+        match quota.clone() {
+            None => {
+                self.oracle_quotas.remove(&account_id);
+            }
+            Some(quota) => {
+                if let Some(record) = self.oracle_quotas.get_mut(&account_id) {
+                    record.quota = quota;
+                } else {
+                    self.oracle_quotas
+                        .insert(account_id.clone(), OracleQuotaRecord::new(quota, now_seconds()));
+                }
+            }
+        }
+
+        emit(EventKind::OracleQuotaChanged(OracleQuotaData { account_id, quota }));
+    }
+
+    fn get_oracle_quotas(&self) -> Vec<(AccountId, OracleQuotaView)> {
+        let now = now_seconds();
+
+        self.oracles
+            .iter()
+            .filter_map(|account_id| {
+                let mut record = self.oracle_quotas.get(account_id)?.clone();
+                record.refresh(now);
+
+                Some((account_id.clone(), record.into()))
+            })
+            .collect()
+    }
+}
+
+impl Contract {
+    pub(crate) fn consume_oracle_quota(&mut self, amount: TokensAmount, entries: u32, now: UnixTimestamp) {
+        let account_id = predecessor_account_id();
+
+        let Some(record) = self.oracle_quotas.get_mut(&account_id) else {
+            return;
+        };
+
+        record.refresh(now);
+
+        if !record.allows(amount, entries) {
+            // The panic reverts the state changes of the call, but its logs stay in the receipt outcome.
+            emit(EventKind::QuotaExceeded(QuotaExceededData {
+                account_id,
+                requested_amount: U128(amount),
+                requested_entries: entries,
+                remaining_amount: U128(record.remaining_amount()),
+                remaining_entries: record.remaining_entries(),
+            }));
+
+            ClaimError::QuotaExceeded.panic();
+        }
+
+        record.consume(amount, entries);
+    }
+}
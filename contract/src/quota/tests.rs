@@ -0,0 +1,153 @@
+#![cfg(test)]
+
+use std::panic::{catch_unwind, AssertUnwindSafe};
+
+use claim_model::{
+    api::{QuotaApi, RecordApi},
+    oracle_quota::OracleQuota,
+};
+use near_sdk::{json_types::U128, test_utils::get_logs};
+
+use crate::common::tests::Context;
+
+fn quota() -> OracleQuota {
+    OracleQuota {
+        max_amount: U128(1_000_000),
+        max_entries: 3,
+        window: 60 * 60,
+    }
+}
+
+#[test]
+fn set_oracle_quota_by_contract_owner() {
+    let (mut context, mut contract, accounts) = Context::init_with_oracle();
+
+    context.switch_account(&accounts.owner);
+    contract.set_oracle_quota(accounts.oracle.clone(), Some(quota()));
+
+    let quotas = contract.get_oracle_quotas();
+    assert_eq!(1, quotas.len());
+
+    let (account_id, view) = &quotas[0];
+    assert_eq!(&accounts.oracle, account_id);
+    assert_eq!(quota(), view.quota);
+    assert_eq!(quota().max_amount, view.remaining_amount);
+    assert_eq!(quota().max_entries, view.remaining_entries);
+
+    let logs = get_logs();
+    assert!(logs.last().unwrap().contains(r#""event":"oracle_quota_changed""#));
+    assert!(logs.last().unwrap().contains(r#""max_entries":3"#));
+
+    contract.set_oracle_quota(accounts.oracle.clone(), None);
+    assert!(contract.get_oracle_quotas().is_empty());
+
+    let logs = get_logs();
+    assert!(logs.last().unwrap().contains(r#""quota":null"#));
+}
+
+#[test]
+#[should_panic(expected = "Method is private")]
+fn set_oracle_quota_not_by_contract_owner() {
+    let (mut context, mut contract, accounts) = Context::init_with_oracle();
+
+    context.switch_account(&accounts.oracle);
+    contract.set_oracle_quota(accounts.oracle.clone(), Some(quota()));
+}
+
+#[test]
+#[should_panic(expected = "No such oracle")]
+fn set_oracle_quota_for_not_oracle() {
+    let (mut context, mut contract, accounts) = Context::init_with_oracle();
+
+    context.switch_account(&accounts.owner);
+    contract.set_oracle_quota(accounts.alice.clone(), Some(quota()));
+}
+
+#[test]
+fn record_within_oracle_quota() {
+    let (mut context, mut contract, accounts) = Context::init_with_oracle();
+
+    context.switch_account(&accounts.owner);
+    contract.set_oracle_quota(accounts.oracle.clone(), Some(quota()));
+
+    context.switch_account(&accounts.oracle);
+    contract.record_batch_for_hold(vec![
+        (accounts.alice.clone(), U128(400_000)),
+        (accounts.bob.clone(), U128(200_000)),
+    ]);
+
+    let (_, view) = &contract.get_oracle_quotas()[0];
+    assert_eq!(400_000, view.remaining_amount.0);
+    assert_eq!(1, view.remaining_entries);
+}
+
+#[test]
+#[should_panic(expected = "Oracle quota exceeded")]
+fn record_over_oracle_quota_amount() {
+    let (mut context, mut contract, accounts) = Context::init_with_oracle();
+
+    context.switch_account(&accounts.owner);
+    contract.set_oracle_quota(accounts.oracle.clone(), Some(quota()));
+
+    context.switch_account(&accounts.oracle);
+    contract.record_batch_for_hold(vec![(accounts.alice.clone(), U128(600_000))]);
+    contract.record_batch_for_hold(vec![(accounts.bob.clone(), U128(600_000))]);
+}
+
+#[test]
+fn record_over_oracle_quota_emits_event() {
+    let (mut context, mut contract, accounts) = Context::init_with_oracle();
+
+    context.switch_account(&accounts.owner);
+    contract.set_oracle_quota(accounts.oracle.clone(), Some(quota()));
+
+    context.switch_account(&accounts.oracle);
+    let result = catch_unwind(AssertUnwindSafe(|| {
+        contract.record_batch_for_hold(vec![(accounts.alice.clone(), U128(1_200_000))]);
+    }));
+    assert!(result.is_err());
+
+    let logs = get_logs();
+    assert!(logs.last().unwrap().contains(r#""event":"quota_exceeded""#));
+    assert!(logs.last().unwrap().contains(r#""requested_amount":"1200000""#));
+    assert!(logs.last().unwrap().contains(r#""remaining_amount":"1000000""#));
+}
+
+#[test]
+#[should_panic(expected = "Oracle quota exceeded")]
+fn record_over_oracle_quota_entries() {
+    let (mut context, mut contract, accounts) = Context::init_with_oracle();
+
+    context.switch_account(&accounts.owner);
+    contract.set_oracle_quota(accounts.oracle.clone(), Some(quota()));
+
+    context.switch_account(&accounts.oracle);
+    contract.record_batch_for_hold(vec![
+        (accounts.alice.clone(), U128(1)),
+        (accounts.bob.clone(), U128(1)),
+        (accounts.alice.clone(), U128(1)),
+        (accounts.bob.clone(), U128(1)),
+    ]);
+}
+
+#[test]
+fn record_after_oracle_quota_window_elapsed() {
+    let (mut context, mut contract, accounts) = Context::init_with_oracle();
+
+    context.switch_account(&accounts.owner);
+    contract.set_oracle_quota(accounts.oracle.clone(), Some(quota()));
+
+    context.switch_account(&accounts.oracle);
+    contract.record_batch_for_hold(vec![(accounts.alice.clone(), U128(900_000))]);
+
+    context.set_block_timestamp_in_seconds(quota().window as u64 + 1);
+
+    let (_, view) = &contract.get_oracle_quotas()[0];
+    assert_eq!(quota().max_amount, view.remaining_amount);
+
+    contract.record_batch_for_hold(vec![(accounts.alice.clone(), U128(900_000))]);
+
+    let (_, view) = &contract.get_oracle_quotas()[0];
+    assert_eq!(100_000, view.remaining_amount.0);
+    assert_eq!(2, view.remaining_entries);
+}
@@ -1,34 +1,49 @@
 use claim_model::{
+    account_history::HistoryEntryKind,
     account_record::AccountRecord,
     api::RecordApi,
     event::{emit, EventKind::Record, RecordData},
 };
-use near_sdk::{json_types::U128, near_bindgen, store::Vector, AccountId};
+use near_sdk::{json_types::U128, near_bindgen, AccountId};
 
-use crate::{common::now_seconds, Contract, ContractExt, StorageKey::AccrualsEntry};
+use crate::{common::now_seconds, Contract, ContractExt};
 
 #[near_bindgen]
 impl RecordApi for Contract {
     fn record_batch_for_hold(&mut self, amounts: Vec<(AccountId, U128)>) {
         self.assert_oracle();
+        Self::assert_event_batch_size(amounts.len());
 
         let now_seconds = now_seconds();
-        let mut event_data = RecordData::new(now_seconds);
 
-        let balances = self
-            .accruals
-            .entry(now_seconds)
-            .or_insert_with(|| (Vector::new(AccrualsEntry(now_seconds)), 0));
+        let total_amount = amounts.iter().map(|(_, amount)| amount.0).sum();
+        self.consume_oracle_quota(total_amount, amounts.len() as u32, now_seconds);
+
+        for (account_id, _) in &amounts {
+            self.prune_accruals(account_id, now_seconds);
+        }
+
+        let mut event_data = RecordData::new(now_seconds);
 
         for (account_id, amount) in amounts {
             event_data.amounts.push((account_id.clone(), amount));
 
+            // Accounts with a burn period override get their own buckets, see `Buckets`.
+            let key = (self.periods(&account_id).burn_period, now_seconds);
+            let (balances, is_created) = self.accruals.get_or_create(key);
+            if is_created {
+                self.bucket_index.insert(&key);
+            }
+
             let amount = amount.0;
             let index = balances.0.len();
 
             balances.1 += amount;
             balances.0.push(amount);
 
+            self.stats.total_held += amount;
+            self.stats.total_recorded += amount;
+
             if let Some(record) = self.accounts.get_mut(&account_id) {
                 record.accruals.push((now_seconds, index));
             } else {
@@ -37,10 +52,18 @@ impl RecordApi for Contract {
                     ..AccountRecord::new(now_seconds)
                 };
 
+                self.account_index.insert(&account_id);
                 self.accounts.insert(account_id, record);
             }
         }
 
+        self.stats.newest_bucket_at = Some(now_seconds);
+        self.stats.oldest_bucket_at.get_or_insert(now_seconds);
+
+        for (account_id, amount) in &event_data.amounts {
+            self.push_history(account_id, HistoryEntryKind::Record, amount.0, now_seconds);
+        }
+
         emit(Record(event_data));
     }
 }
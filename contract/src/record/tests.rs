@@ -1,6 +1,9 @@
 #![cfg(test)]
 
-use claim_model::api::{ClaimApi, ConfigApi, RecordApi};
+use claim_model::{
+    api::{ClaimApi, ConfigApi, RecordApi},
+    event::MAX_EVENT_BATCH_SIZE,
+};
 use near_sdk::{json_types::U128, AccountId};
 
 use crate::common::tests::Context;
@@ -43,6 +46,31 @@ fn record_by_not_oracle() {
     contract.record_batch_for_hold(vec![(accounts.alice.clone(), U128(alice_balance_1))]);
 }
 
+#[test]
+fn record_max_batch() {
+    let (mut context, mut contract, accounts) = Context::init_with_oracle();
+
+    let batch: Vec<_> = (0..MAX_EVENT_BATCH_SIZE)
+        .map(|i| (AccountId::new_unchecked(format!("account_{i}")), U128(1_000)))
+        .collect();
+
+    context.switch_account(&accounts.oracle);
+    contract.record_batch_for_hold(batch);
+
+    assert_eq!(1_000 * MAX_EVENT_BATCH_SIZE as u128, contract.stats.total_held);
+}
+
+#[test]
+#[should_panic(expected = "Batch contains too many accounts")]
+fn record_too_large_batch() {
+    let (mut context, mut contract, accounts) = Context::init_with_oracle();
+
+    let batch = vec![(accounts.alice.clone(), U128(1_000)); MAX_EVENT_BATCH_SIZE + 1];
+
+    context.switch_account(&accounts.oracle);
+    contract.record_batch_for_hold(batch);
+}
+
 #[test]
 fn test_multiple_records_in_the_same_block() {
     let (mut context, mut contract, accounts) = Context::init_with_oracle();
@@ -57,7 +85,7 @@ fn test_multiple_records_in_the_same_block() {
     contract.record_batch_for_hold(batches.get(0).unwrap().clone());
     contract.record_batch_for_hold(batches.get(1).unwrap().clone());
 
-    let accruals = contract.accruals.get(&0).unwrap();
+    let accruals = contract.accruals.get((None, 0)).unwrap();
     assert_eq!(accruals.0.len(), target_accruals.len() as u32);
     assert_eq!(accruals.1, target_accruals.iter().sum::<u128>());
 
@@ -0,0 +1,27 @@
+use claim_model::{api::StatsApi, StatsView};
+use near_sdk::{
+    env,
+    json_types::{U128, U64},
+    near_bindgen,
+};
+
+use crate::{Contract, ContractExt};
+
+#[near_bindgen]
+impl StatsApi for Contract {
+    fn get_stats(&self) -> StatsView {
+        StatsView {
+            total_held: U128(self.stats.total_held),
+            pending_burn: U128(self.pending_burn),
+            buckets_count: self.accruals.len(),
+            oldest_bucket_at: self.stats.oldest_bucket_at,
+            newest_bucket_at: self.stats.newest_bucket_at,
+            accounts_count: self.account_index.len(),
+            total_recorded: U128(self.stats.total_recorded),
+            total_claimed: U128(self.stats.total_claimed),
+            total_burned: U128(self.stats.total_burned),
+            total_revoked: U128(self.stats.total_revoked),
+            storage_usage: U64(env::storage_usage()),
+        }
+    }
+}
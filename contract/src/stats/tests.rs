@@ -0,0 +1,80 @@
+#![cfg(test)]
+
+use claim_model::api::{BurnApi, ClaimApi, RecordApi, StatsApi};
+use near_sdk::json_types::U128;
+
+use crate::{
+    burn::api::test::EXT_BURN_FUTURE,
+    claim::api::test::EXT_TRANSFER_FUTURE,
+    common::tests::{data::set_test_future_success, Context},
+};
+
+#[test]
+fn stats_of_empty_contract() {
+    let (_, contract, _) = Context::init_with_oracle();
+
+    let stats = contract.get_stats();
+    assert_eq!(0, stats.total_held.0);
+    assert_eq!(0, stats.buckets_count);
+    assert_eq!(None, stats.oldest_bucket_at);
+    assert_eq!(None, stats.newest_bucket_at);
+    assert_eq!(0, stats.accounts_count);
+}
+
+#[test]
+fn stats_after_record_claim_and_burn() {
+    let (mut context, mut contract, accounts) = Context::init_with_oracle();
+    set_test_future_success(EXT_TRANSFER_FUTURE, true);
+    set_test_future_success(EXT_BURN_FUTURE, true);
+
+    context.switch_account(&accounts.oracle);
+    contract.record_batch_for_hold(vec![
+        (accounts.alice.clone(), U128(100_000)),
+        (accounts.bob.clone(), U128(200_000)),
+    ]);
+
+    let second_record_timestamp = contract.claim_period + 100;
+    context.set_block_timestamp_in_seconds(second_record_timestamp as u64);
+    contract.record_batch_for_hold(vec![(accounts.alice.clone(), U128(300_000))]);
+
+    let stats = contract.get_stats();
+    assert_eq!(600_000, stats.total_held.0);
+    assert_eq!(600_000, stats.total_recorded.0);
+    assert_eq!(2, stats.buckets_count);
+    assert_eq!(Some(0), stats.oldest_bucket_at);
+    assert_eq!(Some(second_record_timestamp), stats.newest_bucket_at);
+    assert_eq!(2, stats.accounts_count);
+
+    context.switch_account(&accounts.alice);
+    contract.claim();
+
+    let stats = contract.get_stats();
+    assert_eq!(200_000, stats.total_held.0);
+    assert_eq!(400_000, stats.total_claimed.0);
+
+    context.set_block_timestamp_in_seconds(contract.burn_period as u64 + 100);
+    context.switch_account(&accounts.oracle);
+    contract.burn();
+
+    let stats = contract.get_stats();
+    assert_eq!(0, stats.total_held.0);
+    assert_eq!(200_000, stats.total_burned.0);
+    assert_eq!(1, stats.buckets_count);
+    assert_eq!(Some(second_record_timestamp), stats.oldest_bucket_at);
+    assert_eq!(Some(second_record_timestamp), stats.newest_bucket_at);
+}
+
+#[test]
+#[should_panic(expected = "Released amount exceeds the held balance")]
+fn claim_with_inconsistent_held_balance() {
+    let (mut context, mut contract, accounts) = Context::init_with_oracle();
+
+    context.switch_account(&accounts.oracle);
+    contract.record_batch_for_hold(vec![(accounts.alice.clone(), U128(100_000))]);
+
+    contract.stats.total_held = 0;
+
+    context.set_block_timestamp_in_seconds(contract.claim_period as u64 + 100);
+    context.switch_account(&accounts.alice);
+    contract.claim();
+}
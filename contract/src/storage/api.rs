@@ -0,0 +1,308 @@
+use claim_model::{
+    api::StorageApi, failed_claim::ClaimFailureReason, ClaimResultView, StoragePoolView, TokensAmount, UnixTimestamp,
+};
+use near_sdk::{env, json_types::U128, near_bindgen, AccountId, Balance, Gas, PromiseOrValue};
+
+use crate::{Contract, ContractExt};
+
+#[near_bindgen]
+impl StorageApi for Contract {
+    #[payable]
+    fn fund_storage_pool(&mut self) {
+        Self::assert_private();
+
+        self.storage_pool += env::attached_deposit();
+    }
+
+    fn set_storage_deposit_cap(&mut self, cap: Option<U128>) {
+        Self::assert_private();
+
+        self.storage_deposit_cap = cap.map(|cap| cap.0);
+    }
+
+    fn get_storage_pool(&self) -> StoragePoolView {
+        StoragePoolView {
+            balance: U128(self.storage_pool),
+            deposit_cap: self.storage_deposit_cap.map(U128),
+        }
+    }
+}
+
+impl Contract {
+    /// Returns the deposit for a storage registration of the account if the pool can afford it.
+    /// The deposit is the part of the cap the account hasn't been paid by previous registrations.
+    fn affordable_storage_deposit(&self, account_id: &AccountId) -> Option<Balance> {
+        let cap = self.storage_deposit_cap?;
+        let paid = self.storage_subsidies.get(account_id).copied().unwrap_or_default();
+        let deposit = cap.saturating_sub(paid);
+
+        (deposit > 0 && self.storage_pool >= deposit).then_some(deposit)
+    }
+
+    /// The minimum gas a `claim` call of the account must be attached with.
+    pub(crate) fn required_gas_for_claim(&self, account_id: &AccountId) -> Gas {
+        let required = self.gas_config.required_for_claim();
+
+        if self.affordable_storage_deposit(account_id).is_some() {
+            required + self.gas_config.required_for_storage_registration()
+        } else {
+            required
+        }
+    }
+
+    /// Transfers claimed tokens, registering the receiver in the token contract first
+    /// if storage registration is enabled and the pool can pay for it.
+    pub(crate) fn register_storage_and_transfer(
+        &mut self,
+        now: UnixTimestamp,
+        account_id: AccountId,
+        total_accrual: TokensAmount,
+        details: Vec<(UnixTimestamp, TokensAmount)>,
+    ) -> PromiseOrValue<ClaimResultView> {
+        if self.affordable_storage_deposit(&account_id).is_some() {
+            self.storage_balance_of_external(now, account_id, total_accrual, details)
+        } else {
+            self.transfer_external(now, account_id, total_accrual, details)
+        }
+    }
+
+    pub(crate) fn on_storage_balance_of_internal(
+        &mut self,
+        now: UnixTimestamp,
+        account_id: AccountId,
+        total_accrual: TokensAmount,
+        details: Vec<(UnixTimestamp, TokensAmount)>,
+        is_registered: bool,
+    ) -> PromiseOrValue<ClaimResultView> {
+        if is_registered {
+            return self.transfer_external(now, account_id, total_accrual, details);
+        }
+
+        // The pool could have been drained by other claims since the check in `claim`.
+        let Some(deposit) = self.affordable_storage_deposit(&account_id) else {
+            return self.transfer_external(now, account_id, total_accrual, details);
+        };
+
+        self.storage_pool -= deposit;
+
+        self.storage_deposit_external(now, account_id, total_accrual, details, deposit)
+    }
+
+    /// `storage_balance` is the storage balance of the account after a successful registration.
+    pub(crate) fn on_storage_deposit_internal(
+        &mut self,
+        now: UnixTimestamp,
+        account_id: AccountId,
+        total_accrual: TokensAmount,
+        details: Vec<(UnixTimestamp, TokensAmount)>,
+        deposit: Balance,
+        storage_balance: Option<Balance>,
+    ) -> PromiseOrValue<ClaimResultView> {
+        // The token contract refunds the part of the deposit it hasn't used, a failed call returns
+        // the deposit in full.
+        let Some(storage_balance) = storage_balance else {
+            self.storage_pool += deposit;
+
+            return PromiseOrValue::Value(self.fail_claim(
+                now,
+                account_id,
+                total_accrual,
+                details,
+                ClaimFailureReason::StorageRegistrationFailed,
+            ));
+        };
+
+        let refund = deposit.saturating_sub(storage_balance);
+        self.storage_pool += refund;
+        *self.storage_subsidies.entry(account_id.clone()).or_default() += deposit - refund;
+
+        self.transfer_external(now, account_id, total_accrual, details)
+    }
+}
+
+#[cfg(not(test))]
+mod prod {
+    use claim_model::{ClaimResultView, TokensAmount, UnixTimestamp};
+    use near_sdk::{
+        env, ext_contract,
+        json_types::U128,
+        near_bindgen,
+        serde::Deserialize,
+        serde_json::{self, json},
+        AccountId, Balance, GasWeight, Promise, PromiseOrValue, PromiseResult,
+    };
+
+    use crate::{Contract, ContractExt};
+
+    /// The part of NEP-145 `StorageBalance` used by the contract.
+    #[derive(Deserialize)]
+    #[serde(crate = "near_sdk::serde")]
+    struct StorageBalance {
+        total: U128,
+    }
+
+    fn storage_balance_result() -> Option<Option<StorageBalance>> {
+        match env::promise_result(0) {
+            PromiseResult::Successful(value) => serde_json::from_slice(&value).ok(),
+            _ => None,
+        }
+    }
+
+    #[ext_contract(ext_self)]
+    pub trait SelfCallback {
+        fn on_storage_balance_of(
+            &mut self,
+            now: UnixTimestamp,
+            account_id: AccountId,
+            total_accrual: TokensAmount,
+            details: Vec<(UnixTimestamp, TokensAmount)>,
+        ) -> PromiseOrValue<ClaimResultView>;
+
+        fn on_storage_deposit(
+            &mut self,
+            now: UnixTimestamp,
+            account_id: AccountId,
+            total_accrual: TokensAmount,
+            details: Vec<(UnixTimestamp, TokensAmount)>,
+            deposit: Balance,
+        ) -> PromiseOrValue<ClaimResultView>;
+    }
+
+    #[near_bindgen]
+    impl SelfCallback for Contract {
+        #[private]
+        fn on_storage_balance_of(
+            &mut self,
+            now: UnixTimestamp,
+            account_id: AccountId,
+            total_accrual: TokensAmount,
+            details: Vec<(UnixTimestamp, TokensAmount)>,
+        ) -> PromiseOrValue<ClaimResultView> {
+            // If the registration can't be checked, the transfer is attempted anyway.
+            let is_registered = !matches!(storage_balance_result(), Some(None));
+
+            self.on_storage_balance_of_internal(now, account_id, total_accrual, details, is_registered)
+        }
+
+        #[private]
+        fn on_storage_deposit(
+            &mut self,
+            now: UnixTimestamp,
+            account_id: AccountId,
+            total_accrual: TokensAmount,
+            details: Vec<(UnixTimestamp, TokensAmount)>,
+            deposit: Balance,
+        ) -> PromiseOrValue<ClaimResultView> {
+            let storage_balance = storage_balance_result().flatten().map(|balance| balance.total.0);
+
+            self.on_storage_deposit_internal(now, account_id, total_accrual, details, deposit, storage_balance)
+        }
+    }
+
+    impl Contract {
+        pub(crate) fn storage_balance_of_external(
+            &mut self,
+            now: UnixTimestamp,
+            account_id: AccountId,
+            total_accrual: TokensAmount,
+            details: Vec<(UnixTimestamp, TokensAmount)>,
+        ) -> PromiseOrValue<ClaimResultView> {
+            let args = json!({ "account_id": account_id }).to_string().as_bytes().to_vec();
+
+            Promise::new(self.token_account_id.clone())
+                .function_call_weight(
+                    "storage_balance_of".to_string(),
+                    args,
+                    0,
+                    self.gas_config.storage_registration,
+                    GasWeight(0),
+                )
+                .then(
+                    ext_self::ext(env::current_account_id())
+                        .with_static_gas(self.gas_config.storage_registration_callback)
+                        .with_unused_gas_weight(1)
+                        .on_storage_balance_of(now, account_id, total_accrual, details),
+                )
+                .into()
+        }
+
+        pub(crate) fn storage_deposit_external(
+            &mut self,
+            now: UnixTimestamp,
+            account_id: AccountId,
+            total_accrual: TokensAmount,
+            details: Vec<(UnixTimestamp, TokensAmount)>,
+            deposit: Balance,
+        ) -> PromiseOrValue<ClaimResultView> {
+            let args = json!({
+                "account_id": account_id,
+                "registration_only": true,
+            })
+            .to_string()
+            .as_bytes()
+            .to_vec();
+
+            Promise::new(self.token_account_id.clone())
+                .function_call_weight(
+                    "storage_deposit".to_string(),
+                    args,
+                    deposit,
+                    self.gas_config.storage_registration,
+                    GasWeight(0),
+                )
+                .then(
+                    ext_self::ext(env::current_account_id())
+                        .with_static_gas(self.gas_config.storage_registration_callback)
+                        .with_unused_gas_weight(1)
+                        .on_storage_deposit(now, account_id, total_accrual, details, deposit),
+                )
+                .into()
+        }
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod test {
+    use claim_model::{ClaimResultView, TokensAmount, UnixTimestamp};
+    use near_sdk::{AccountId, Balance, PromiseOrValue};
+
+    use crate::{common::tests::data::get_test_future_success, Contract};
+
+    /// Whether the receiver is registered in the token contract.
+    pub(crate) const EXT_STORAGE_BALANCE_OF_FUTURE: &str = "ext_storage_balance_of";
+    pub(crate) const EXT_STORAGE_DEPOSIT_FUTURE: &str = "ext_storage_deposit";
+
+    /// The storage balance the test token contract keeps from a registration deposit.
+    pub(crate) const TEST_STORAGE_BALANCE: Balance = 1_250_000_000_000_000_000_000;
+
+    impl Contract {
+        pub(crate) fn storage_balance_of_external(
+            &mut self,
+            now: UnixTimestamp,
+            account_id: AccountId,
+            total_accrual: TokensAmount,
+            details: Vec<(UnixTimestamp, TokensAmount)>,
+        ) -> PromiseOrValue<ClaimResultView> {
+            self.on_storage_balance_of_internal(
+                now,
+                account_id,
+                total_accrual,
+                details,
+                get_test_future_success(EXT_STORAGE_BALANCE_OF_FUTURE),
+            )
+        }
+
+        pub(crate) fn storage_deposit_external(
+            &mut self,
+            now: UnixTimestamp,
+            account_id: AccountId,
+            total_accrual: TokensAmount,
+            details: Vec<(UnixTimestamp, TokensAmount)>,
+            deposit: Balance,
+        ) -> PromiseOrValue<ClaimResultView> {
+            let storage_balance = get_test_future_success(EXT_STORAGE_DEPOSIT_FUTURE).then_some(TEST_STORAGE_BALANCE);
+
+            self.on_storage_deposit_internal(now, account_id, total_accrual, details, deposit, storage_balance)
+        }
+    }
+}
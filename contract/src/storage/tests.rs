@@ -0,0 +1,179 @@
+#![cfg(test)]
+
+use claim_model::{
+    api::{ClaimApi, RecordApi, StorageApi},
+    failed_claim::ClaimFailureReason,
+    StoragePoolView,
+};
+use near_sdk::{json_types::U128, Balance, Gas, ONE_NEAR};
+
+use crate::{
+    claim::api::test::EXT_TRANSFER_FUTURE,
+    common::tests::{data::set_test_future_success, Context, TestAccounts},
+    storage::api::test::{EXT_STORAGE_BALANCE_OF_FUTURE, EXT_STORAGE_DEPOSIT_FUTURE, TEST_STORAGE_BALANCE},
+    Contract,
+};
+
+const DEPOSIT_CAP: Balance = ONE_NEAR / 100;
+
+fn init_with_storage_pool() -> (Context, Contract, TestAccounts) {
+    let (mut context, mut contract, accounts) = Context::init_with_oracle();
+
+    context.switch_account(&accounts.owner);
+    context.set_attached_deposit(ONE_NEAR);
+    contract.fund_storage_pool();
+    context.set_attached_deposit(0);
+    contract.set_storage_deposit_cap(Some(U128(DEPOSIT_CAP)));
+
+    context.switch_account(&accounts.oracle);
+    contract.record_batch_for_hold(vec![(accounts.alice.clone(), U128(100_000))]);
+    context.set_block_timestamp_in_seconds(contract.claim_period as u64 + 1);
+
+    (context, contract, accounts)
+}
+
+#[test]
+fn fund_storage_pool_by_owner() {
+    let (_, contract, _) = init_with_storage_pool();
+
+    assert_eq!(
+        StoragePoolView {
+            balance: U128(ONE_NEAR),
+            deposit_cap: Some(U128(DEPOSIT_CAP)),
+        },
+        contract.get_storage_pool()
+    );
+}
+
+#[test]
+#[should_panic(expected = "Method is private")]
+fn fund_storage_pool_by_not_owner() {
+    let (mut context, mut contract, accounts) = Context::init_with_oracle();
+
+    context.switch_account(&accounts.oracle);
+    context.set_attached_deposit(ONE_NEAR);
+    contract.fund_storage_pool();
+}
+
+#[test]
+#[should_panic(expected = "Method is private")]
+fn set_storage_deposit_cap_by_not_owner() {
+    let (mut context, mut contract, accounts) = Context::init_with_oracle();
+
+    context.switch_account(&accounts.oracle);
+    contract.set_storage_deposit_cap(Some(U128(DEPOSIT_CAP)));
+}
+
+#[test]
+fn claim_registers_unregistered_receiver() {
+    let (mut context, mut contract, accounts) = init_with_storage_pool();
+    set_test_future_success(EXT_STORAGE_BALANCE_OF_FUTURE, false);
+    set_test_future_success(EXT_STORAGE_DEPOSIT_FUTURE, true);
+    set_test_future_success(EXT_TRANSFER_FUTURE, true);
+
+    context.switch_account(&accounts.alice);
+    contract.claim();
+
+    assert_eq!(0, contract.get_claimable_balance_for_account(accounts.alice.clone()).0);
+    assert_eq!(ONE_NEAR - TEST_STORAGE_BALANCE, contract.get_storage_pool().balance.0);
+}
+
+#[test]
+fn claim_does_not_register_account_paid_the_cap_again() {
+    let (mut context, mut contract, accounts) = init_with_storage_pool();
+    set_test_future_success(EXT_STORAGE_BALANCE_OF_FUTURE, false);
+    set_test_future_success(EXT_STORAGE_DEPOSIT_FUTURE, true);
+    set_test_future_success(EXT_TRANSFER_FUTURE, true);
+
+    context.switch_account(&accounts.owner);
+    contract.set_storage_deposit_cap(Some(U128(TEST_STORAGE_BALANCE)));
+
+    context.switch_account(&accounts.alice);
+    contract.claim();
+
+    assert_eq!(ONE_NEAR - TEST_STORAGE_BALANCE, contract.get_storage_pool().balance.0);
+    assert_eq!(
+        Some(&TEST_STORAGE_BALANCE),
+        contract.storage_subsidies.get(&accounts.alice)
+    );
+
+    // The account has unregistered from the token contract and claims again.
+    context.switch_account(&accounts.oracle);
+    contract.record_batch_for_hold(vec![(accounts.alice.clone(), U128(100_000))]);
+    context.set_block_timestamp_in_seconds(2 * (contract.claim_period as u64 + 1));
+
+    context.switch_account(&accounts.alice);
+    context.set_prepaid_gas(contract.gas_config.required_for_claim());
+    contract.claim();
+
+    assert_eq!(0, contract.get_claimable_balance_for_account(accounts.alice.clone()).0);
+    assert_eq!(ONE_NEAR - TEST_STORAGE_BALANCE, contract.get_storage_pool().balance.0);
+    assert_eq!(
+        Some(&TEST_STORAGE_BALANCE),
+        contract.storage_subsidies.get(&accounts.alice)
+    );
+}
+
+#[test]
+fn claim_to_registered_receiver_does_not_spend_pool() {
+    let (mut context, mut contract, accounts) = init_with_storage_pool();
+    set_test_future_success(EXT_STORAGE_BALANCE_OF_FUTURE, true);
+    set_test_future_success(EXT_TRANSFER_FUTURE, true);
+
+    context.switch_account(&accounts.alice);
+    contract.claim();
+
+    assert_eq!(0, contract.get_claimable_balance_for_account(accounts.alice.clone()).0);
+    assert_eq!(ONE_NEAR, contract.get_storage_pool().balance.0);
+}
+
+#[test]
+fn claim_fails_when_storage_registration_fails() {
+    let (mut context, mut contract, accounts) = init_with_storage_pool();
+    set_test_future_success(EXT_STORAGE_BALANCE_OF_FUTURE, false);
+    set_test_future_success(EXT_STORAGE_DEPOSIT_FUTURE, false);
+
+    context.switch_account(&accounts.alice);
+    contract.claim();
+
+    assert_eq!(
+        100_000,
+        contract.get_claimable_balance_for_account(accounts.alice.clone()).0
+    );
+    assert_eq!(ONE_NEAR, contract.get_storage_pool().balance.0);
+    assert_eq!(
+        ClaimFailureReason::StorageRegistrationFailed,
+        contract.get_failed_claim(accounts.alice.clone()).unwrap().reason
+    );
+    assert!(contract.accounts.get(&accounts.alice).unwrap().locked_at.is_none());
+}
+
+#[test]
+fn claim_skips_registration_when_pool_is_exhausted() {
+    let (mut context, mut contract, accounts) = init_with_storage_pool();
+    set_test_future_success(EXT_STORAGE_BALANCE_OF_FUTURE, false);
+    set_test_future_success(EXT_TRANSFER_FUTURE, false);
+
+    contract.storage_pool = DEPOSIT_CAP - 1;
+
+    context.switch_account(&accounts.alice);
+    contract.claim();
+
+    assert_eq!(DEPOSIT_CAP - 1, contract.get_storage_pool().balance.0);
+    assert_eq!(
+        ClaimFailureReason::TransferFailed,
+        contract.get_failed_claim(accounts.alice.clone()).unwrap().reason
+    );
+}
+
+#[test]
+#[should_panic(expected = "Not enough gas attached")]
+fn claim_with_storage_registration_and_not_enough_gas() {
+    let (mut context, mut contract, accounts) = init_with_storage_pool();
+
+    context.switch_account(&accounts.alice);
+    context.set_prepaid_gas(
+        contract.gas_config.required_for_claim() + contract.gas_config.required_for_storage_registration() - Gas(1),
+    );
+    contract.claim();
+}
@@ -2,19 +2,23 @@
 
 use anyhow::Result;
 use claim_model::{
-    api::{BurnApiIntegration, ClaimApiIntegration},
+    api::{
+        BurnApiIntegration, ClaimApiIntegration, CleanApiIntegration, ConfigApiIntegration, InvariantApiIntegration,
+        StatsApiIntegration,
+    },
     ClaimAvailabilityView,
 };
 use integration_utils::misc::ToNear;
 use near_sdk::{
     json_types::{U128, U64},
     serde_json::json,
+    AccountId,
 };
 use sweat_model::{FungibleTokenCoreIntegration, Payout, SweatApiIntegration, SweatContract, SweatDeferIntegration};
 
 use crate::{
     common::PanicFinder,
-    prepare::{prepare_contract, IntegrationContext, BURN_PERIOD, CLAIM_PERIOD},
+    prepare::{prepare_contract, Context, IntegrationContext, BURN_PERIOD, CLAIM_PERIOD},
 };
 
 mod common;
@@ -210,6 +214,192 @@ async fn outdate() -> anyhow::Result<()> {
     Ok(())
 }
 
+#[tokio::test]
+async fn failed_claim_interleaved_with_burn() -> anyhow::Result<()> {
+    let mut context = prepare_contract().await?;
+
+    let manager = context.manager().await?;
+    // Bob isn't registered in the token contract, so transfers to him fail.
+    let bob = context.bob().await?;
+
+    context
+        .ft_contract()
+        .defer_batch(
+            vec![(bob.to_near(), 10_000)],
+            context.sweat_claim().contract.as_account().to_near(),
+        )
+        .with_user(&manager)
+        .await?;
+
+    context
+        .fast_forward_hours((CLAIM_PERIOD / (60 * 60) + 1) as u64)
+        .await?;
+
+    let claim = bob
+        .call(context.sweat_claim().contract.as_account().id(), "claim")
+        .max_gas()
+        .transact_async()
+        .await?;
+
+    context.sweat_claim().set_burn_period(1).with_user(&manager).await?;
+    context.sweat_claim().burn().with_user(&manager).await?;
+
+    claim.await?;
+
+    assert_balance_is_consistent(&context, &[bob.to_near()]).await?;
+
+    context.sweat_claim().burn().with_user(&manager).await?;
+
+    let stats = context.sweat_claim().get_stats().await?;
+    assert_eq!(0, stats.total_held.0);
+    assert_eq!(0, stats.pending_burn.0);
+    assert_balance_is_consistent(&context, &[bob.to_near()]).await?;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn claim_interleaved_with_burn() -> anyhow::Result<()> {
+    let mut context = prepare_contract().await?;
+
+    let manager = context.manager().await?;
+    let alice = context.alice().await?;
+    let alice_initial_balance = context.ft_contract().ft_balance_of(alice.to_near()).await?;
+
+    context
+        .ft_contract()
+        .defer_batch(
+            vec![(alice.to_near(), 10_000)],
+            context.sweat_claim().contract.as_account().to_near(),
+        )
+        .with_user(&manager)
+        .await?;
+
+    let alice_deferred_balance = context
+        .sweat_claim()
+        .get_claimable_balance_for_account(alice.to_near())
+        .await?;
+
+    context
+        .fast_forward_hours((CLAIM_PERIOD / (60 * 60) + 1) as u64)
+        .await?;
+
+    let claim = alice
+        .call(context.sweat_claim().contract.as_account().id(), "claim")
+        .max_gas()
+        .transact_async()
+        .await?;
+
+    context.sweat_claim().set_burn_period(1).with_user(&manager).await?;
+    let burn_result = context.sweat_claim().burn().with_user(&manager).await?;
+
+    claim.await?;
+
+    let alice_balance = context.ft_contract().ft_balance_of(alice.to_near()).await?;
+    let stats = context.sweat_claim().get_stats().await?;
+
+    // The tokens are either claimed or burnt, but never both.
+    assert_eq!(
+        alice_deferred_balance.0,
+        alice_balance.0 - alice_initial_balance.0 + burn_result.0 + stats.pending_burn.0
+    );
+    assert_balance_is_consistent(&context, &[alice.to_near()]).await?;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn clean_accounts() -> anyhow::Result<()> {
+    let mut context = prepare_contract().await?;
+
+    let alice = context.alice().await?;
+    let bob = context.bob().await?;
+    let manager = context.manager().await?;
+
+    context
+        .ft_contract()
+        .defer_batch(
+            vec![(alice.to_near(), 10_000), (bob.to_near(), 20_000)],
+            context.sweat_claim().contract.as_account().to_near(),
+        )
+        .with_user(&manager)
+        .await?;
+
+    let alice_balance = context
+        .sweat_claim()
+        .get_claimable_balance_for_account(alice.to_near())
+        .await?;
+    let bob_balance = context
+        .sweat_claim()
+        .get_claimable_balance_for_account(bob.to_near())
+        .await?;
+
+    let preview = context
+        .sweat_claim()
+        .clean_preview(vec![alice.to_near(), bob.to_near()])
+        .await?;
+    assert_eq!(
+        vec![(alice.to_near(), alice_balance), (bob.to_near(), bob_balance)],
+        preview
+    );
+
+    let account_ids = vec![alice.to_near(), bob.to_near()];
+
+    let next_from = context.sweat_claim().clean_paginated(0, 1).with_user(&manager).await?;
+    assert_eq!(Some(1), next_from);
+
+    let stats = context.sweat_claim().get_stats().await?;
+    assert_eq!(alice_balance, stats.pending_burn);
+    assert_balance_is_consistent(&context, &account_ids).await?;
+
+    context
+        .sweat_claim()
+        .clean(vec![bob.to_near()])
+        .with_user(&manager)
+        .await?;
+
+    let preview = context.sweat_claim().clean_preview(account_ids.clone()).await?;
+    assert_eq!(vec![(alice.to_near(), U128(0)), (bob.to_near(), U128(0))], preview);
+
+    assert_balance_is_consistent(&context, &account_ids).await?;
+
+    context.sweat_claim().burn().with_user(&manager).await?;
+
+    let stats = context.sweat_claim().get_stats().await?;
+    assert_eq!(0, stats.total_held.0);
+    assert_eq!(0, stats.accounts_count);
+    assert_balance_is_consistent(&context, &account_ids).await?;
+
+    Ok(())
+}
+
+/// Checks that the tokens held by the contract match its accounting.
+async fn assert_balance_is_consistent(context: &Context, account_ids: &[AccountId]) -> anyhow::Result<()> {
+    let contract_balance = context
+        .ft_contract()
+        .ft_balance_of(context.sweat_claim().contract.as_account().to_near())
+        .await?;
+    let stats = context.sweat_claim().get_stats().await?;
+
+    let mut total_claimable = 0;
+    for account_id in account_ids {
+        total_claimable += context
+            .sweat_claim()
+            .get_claimable_balance_for_account(account_id.clone())
+            .await?
+            .0;
+    }
+
+    assert_eq!(contract_balance, stats.total_held);
+    assert!(stats.total_held.0 >= total_claimable + stats.pending_burn.0);
+
+    let report = context.sweat_claim().check_invariants(None, 100).await?;
+    assert!(report.is_ok(), "{report:?}");
+    assert_eq!(Some(true), report.is_held_balance_consistent);
+
+    Ok(())
+}
+
 #[tokio::test]
 async fn on_burn_direct_call() -> anyhow::Result<()> {
     let mut context = prepare_contract().await?;
@@ -1,13 +1,14 @@
 #![cfg(test)]
 
 use std::future::IntoFuture;
+
 use anyhow::Result;
+use claim_model::{api::RecordApiIntegration, event::MAX_EVENT_BATCH_SIZE};
 use integration_utils::measure::{
     measure::scoped_command_measure,
     outcome_storage::OutcomeStorage,
     utils::{pretty_gas_string, values_diff},
 };
-use claim_model::api::RecordApiIntegration;
 use near_sdk::{json_types::U128, AccountId};
 use near_workspaces::types::Gas;
 
@@ -16,7 +17,7 @@ use crate::{prepare::IntegrationContext, prepare_contract};
 #[ignore]
 #[tokio::test]
 async fn measure_record_batch_for_hold_test() -> Result<()> {
-    let multiplier: u64 = 50;
+    let multiplier: u64 = 30;
 
     let measured = scoped_command_measure(
         (0..5).map(|i| i * multiplier as usize).collect::<Vec<_>>(),
@@ -50,7 +51,7 @@ async fn measure_record_batch_for_hold_test() -> Result<()> {
 #[ignore]
 #[tokio::test]
 async fn single_record_batch_for_hold() -> Result<()> {
-    let gas = measure_record_batch_for_hold(300).await?;
+    let gas = measure_record_batch_for_hold(MAX_EVENT_BATCH_SIZE).await?;
 
     dbg!(&gas.as_tgas());
 
@@ -76,8 +77,8 @@ async fn measure_record_batch_for_hold(count: usize) -> Result<Gas> {
         context
             .sweat_claim()
             .record_batch_for_hold(records)
-            .with_user(&oracle).into_future()
-            ,
+            .with_user(&oracle)
+            .into_future(),
     )
     .await?;
 
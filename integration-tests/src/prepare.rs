@@ -22,6 +22,7 @@ pub type Context = integration_utils::context::Context<near_workspaces::network:
 pub trait IntegrationContext {
     async fn manager(&mut self) -> anyhow::Result<Account>;
     async fn alice(&mut self) -> anyhow::Result<Account>;
+    async fn bob(&mut self) -> anyhow::Result<Account>;
     fn sweat_claim(&self) -> ClaimContract;
     fn ft_contract(&self) -> SweatContract;
 }
@@ -36,6 +37,10 @@ impl IntegrationContext for Context {
         self.account("alice").await
     }
 
+    async fn bob(&mut self) -> anyhow::Result<Account> {
+        self.account("bob").await
+    }
+
     fn sweat_claim(&self) -> ClaimContract {
         ClaimContract { contract: &self.contracts[SWEAT_CLAIM] }
     }
@@ -0,0 +1,61 @@
+use near_sdk::{
+    borsh,
+    borsh::{BorshDeserialize, BorshSerialize},
+    serde::{Deserialize, Serialize},
+};
+#[cfg(feature = "schema")]
+use schemars::JsonSchema;
+
+use crate::{TokensAmount, UnixTimestamp};
+
+/// A type of operation affecting an account's balance.
+#[derive(BorshDeserialize, BorshSerialize, Serialize, Deserialize, Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+#[serde(crate = "near_sdk::serde", rename_all = "snake_case")]
+pub enum HistoryEntryKind {
+    /// Tokens were recorded for the account by an oracle.
+    Record,
+    /// Tokens were claimed by the account.
+    Claim,
+    /// Tokens were not claimed within the burn period and expired. They are burnt by the next `burn`.
+    Expire,
+}
+
+/// A single operation in an account's activity log.
+#[derive(BorshDeserialize, BorshSerialize)]
+pub struct HistoryEntry {
+    pub timestamp: UnixTimestamp,
+    pub kind: HistoryEntryKind,
+    pub amount: TokensAmount,
+}
+
+/// Represents a bounded activity log of an account.
+///
+/// `AccountHistory` works as a ring buffer: once the number of stored entries exceeds
+/// the configured depth, the oldest entries are dropped. Every entry has a sequential index
+/// which stays the same for the entry's lifetime, so clients can paginate through the log.
+#[derive(BorshDeserialize, BorshSerialize, Default)]
+pub struct AccountHistory {
+    /// The most recent entries, from the oldest to the newest.
+    pub entries: Vec<HistoryEntry>,
+
+    /// The total number of entries ever added to the log.
+    pub total: u32,
+}
+
+impl AccountHistory {
+    pub fn push(&mut self, entry: HistoryEntry, depth: u32) {
+        self.entries.push(entry);
+        self.total += 1;
+
+        let depth = depth as usize;
+        if self.entries.len() > depth {
+            self.entries.drain(..self.entries.len() - depth);
+        }
+    }
+
+    /// Returns an index of the oldest stored entry.
+    pub fn first_index(&self) -> u32 {
+        self.total - self.entries.len() as u32
+    }
+}
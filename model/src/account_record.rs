@@ -3,20 +3,24 @@ use near_sdk::{
     borsh::{BorshDeserialize, BorshSerialize},
 };
 
-use crate::{AccrualIndex, UnixTimestamp};
+use crate::{AccrualIndex, Duration, UnixTimestamp};
 
 /// Represents the state of a registered account in the smart contract.
 ///
 /// `AccountRecord` maintains the status and history of an individual user's account within
 /// the smart contract. It tracks various aspects of the account, such as accrual references,
 /// claim history, and operational states.
-#[derive(BorshDeserialize, BorshSerialize)]
+#[derive(BorshDeserialize, BorshSerialize, Clone)]
 pub struct AccountRecord {
     /// A list of references to accrual entries in `Contract.accruals`.
     ///
     /// `accruals` contains pairs of timestamps and indices that link to specific accrual
     /// records in the contract's accruals ledger. These references are used to calculate
-    /// and verify the user's accrued token amount.
+    /// and verify the user's accrued token amount. If the account has a burn period override,
+    /// the references point to the buckets of that period.
+    ///
+    /// References to burnt and expired entries are pruned when the account is touched by
+    /// a record or a claim, and by `AccountApi::prune_accounts`.
     ///
     /// Here is an illustration of the connection:
     /// ```text
@@ -34,9 +38,8 @@ pub struct AccountRecord {
 
     /// Indicates whether the user is authorized to use the contract's features.
     ///
-    /// Currently, `is_enabled` is not actively used but is prepared for future releases.
-    /// It can be used to enable or disable access to contract functionalities for this
-    /// particular account.
+    /// `is_enabled` is switched off by an oracle to freeze the account, e.g. during a fraud
+    /// review. A disabled account keeps accruing tokens but cannot claim them.
     pub is_enabled: bool,
 
     /// The timestamp of the last event that resets claim period.
@@ -47,12 +50,14 @@ pub struct AccountRecord {
     /// It is used to determine eligibility for future claims.
     pub claim_period_refreshed_at: UnixTimestamp,
 
-    /// Indicates whether there is an active operation on the user's balance.
+    /// The timestamp when the active operation on the user's balance has started, if any.
     ///
-    /// `is_locked` is used to signal if the account is currently engaged in an operation
+    /// `locked_at` is used to signal if the account is currently engaged in an operation
     /// that affects its balance, such as a claim process. This is important for ensuring
     /// the integrity of account operations and preventing concurrent modifications.
-    pub is_locked: bool,
+    /// The lock counts as released once `Contract.lock_expiry` has passed, so the account
+    /// doesn't stay locked for good if the operation's callback never runs.
+    pub locked_at: Option<UnixTimestamp>,
 }
 
 impl AccountRecord {
@@ -61,7 +66,60 @@ impl AccountRecord {
             accruals: Vec::new(),
             is_enabled: true,
             claim_period_refreshed_at: now,
-            is_locked: false,
+            locked_at: None,
+        }
+    }
+}
+
+/// The periods of an account overriding the contract-wide ones.
+///
+/// `AccountPeriods` is stored apart from `AccountRecord`, so records don't change their layout
+/// and accounts without overrides don't pay for them.
+#[derive(BorshDeserialize, BorshSerialize, Clone, Copy, Default)]
+pub struct AccountPeriods {
+    /// The account's own claim period overriding `Contract.claim_period`, if any.
+    pub claim_period: Option<Duration>,
+
+    /// The account's own burn period overriding `Contract.burn_period`, if any.
+    ///
+    /// Accruals of accounts with a burn period override are kept in buckets of that period,
+    /// so buckets of other accounts are burnt on `Contract.burn_period`.
+    pub burn_period: Option<Duration>,
+}
+
+impl AccountPeriods {
+    /// Returns the claim period of the account, falling back to the contract-wide `default`.
+    pub fn claim_period_or(&self, default: Duration) -> Duration {
+        self.claim_period.unwrap_or(default)
+    }
+
+    /// Returns the burn period of the account, falling back to the contract-wide `default`.
+    pub fn burn_period_or(&self, default: Duration) -> Duration {
+        self.burn_period.unwrap_or(default)
+    }
+}
+
+/// The layout of `AccountRecord` stored by the contract before locks started to expire.
+///
+/// Records of this layout stay under their original storage prefix after the migration
+/// and are converted to `AccountRecord` when the account is indexed or touched.
+#[derive(BorshDeserialize, BorshSerialize, Clone)]
+pub struct LegacyAccountRecord {
+    pub accruals: Vec<(UnixTimestamp, AccrualIndex)>,
+    pub is_enabled: bool,
+    pub claim_period_refreshed_at: UnixTimestamp,
+    pub is_locked: bool,
+}
+
+impl LegacyAccountRecord {
+    /// Converts the record to the current layout. A lock held by the previous version
+    /// is considered taken at `now`, so it expires after the contract's lock expiry.
+    pub fn upgrade(self, now: UnixTimestamp) -> AccountRecord {
+        AccountRecord {
+            accruals: self.accruals,
+            is_enabled: self.is_enabled,
+            claim_period_refreshed_at: self.claim_period_refreshed_at,
+            locked_at: self.is_locked.then_some(now),
         }
     }
 }
@@ -1,14 +1,19 @@
+use std::collections::HashMap;
+
 use integration_trait::make_integration_version;
 use near_sdk::{json_types::U128, AccountId, PromiseOrValue};
 
-use crate::{ClaimAvailabilityView, ClaimResultView, Duration};
+use crate::{
+    gas_config::GasConfig, oracle_quota::OracleQuota, AccountView, AccountsPageView, ClaimAvailabilityView,
+    ClaimAvailabilityViewV2, ClaimResultView, Duration, FailedClaimView, HistoryEntryView, InvariantCursor,
+    InvariantReport, OracleQuotaView, PruneResultView, StatsView, StoragePoolView, UnixTimestamp,
+};
 
 #[cfg(feature = "integration-test")]
 pub struct ClaimContract<'a> {
     pub contract: &'a near_workspaces::Contract,
 }
 
-
 /// An API for initializing smart contracts in the context of fungible token operations.
 ///
 /// This API provides a method to initialize the smart contract, primarily for interactions
@@ -29,6 +34,31 @@ pub trait InitApi {
     ///
     /// Returns an instance of the implementing type.
     fn init(token_account_id: AccountId) -> Self;
+
+    /// Migrates the state of a contract deployed before the account index and the contract-wide
+    /// settings were introduced.
+    ///
+    /// This method must be called in the same transaction as the deployment of the new code.
+    /// It reads the state stored by the previous version, fills the new settings with their
+    /// initial values and starts the account index with the provided accounts. The accounts
+    /// which don't fit into a single call are added afterwards with `AccountApi::index_accounts`,
+    /// or when their records are first changed.
+    ///
+    /// # Arguments
+    ///
+    /// * `account_ids` - A vector of `AccountId`s registered in the contract, collected e.g. from
+    ///   `record` events. Accounts which are not registered in the contract are ignored.
+    ///
+    /// # Returns
+    ///
+    /// Returns an instance of the implementing type.
+    ///
+    /// # Panics
+    ///
+    /// Panics if called by any entity other than the contract itself.
+    ///
+    /// Panics if the contract has no state to migrate from.
+    fn migrate(account_ids: Vec<AccountId>) -> Self;
 }
 
 /// An API for configuring various parameters of the smart contract during its lifetime.
@@ -66,6 +96,132 @@ pub trait ConfigApi {
     ///
     /// Panics if called by any entity other than the oracle.
     fn set_burn_period(&mut self, period: Duration);
+
+    /// Sets the maximum number of entries kept in each account's activity log.
+    ///
+    /// Once an account's log reaches `depth` entries, the oldest ones are dropped as new
+    /// entries are added. Setting `depth` to zero stops recording of the activity logs.
+    /// This method emits a `HistoryDepthChanged` event.
+    ///
+    /// # Arguments
+    ///
+    /// * `depth` - The maximum number of entries in an account's activity log.
+    ///
+    /// # Panics
+    ///
+    /// Panics if called by any entity other than the oracle.
+    fn set_history_depth(&mut self, depth: u32);
+
+    /// Sets the time after which locks of accounts are released.
+    ///
+    /// An account is locked by a claim waiting for its cross-contract callback. If the callback
+    /// is never executed, the lock is considered released once `expiry` has passed since it was
+    /// taken. The lock of a burn doesn't expire, it's released with `LockApi::reset_service_call`.
+    /// This method emits a `LockExpiryChanged` event.
+    ///
+    /// # Arguments
+    ///
+    /// * `expiry` - A `Duration` representing the lifetime of a lock in seconds.
+    ///
+    /// # Panics
+    ///
+    /// Panics if called by any entity other than the contract itself.
+    ///
+    /// Panics if `expiry` is shorter than 10 minutes, so an account can't be unlocked while
+    /// its claim is in flight.
+    fn set_lock_expiry(&mut self, expiry: Duration);
+
+    /// Sets the gas attached to cross-contract calls made by `claim` and `burn`.
+    ///
+    /// `claim` and `burn` reject calls attached with less gas than the sum of the gas of the
+    /// cross-contract call, of its callback and of the execution reserve. This method emits
+    /// a `GasConfigChanged` event.
+    ///
+    /// # Arguments
+    ///
+    /// * `gas_config` - A `GasConfig` containing gas amounts for the calls and their callbacks.
+    ///
+    /// # Panics
+    ///
+    /// Panics if called by any entity other than the contract itself.
+    ///
+    /// Panics if any call gets less than 5 TGas, or if a claim registering its receiver or a burn
+    /// would require more than 300 TGas.
+    fn set_gas_config(&mut self, gas_config: GasConfig);
+
+    /// Retrieves the gas attached to cross-contract calls made by `claim` and `burn`.
+    fn get_gas_config(&self) -> GasConfig;
+}
+
+/// An API for recovering from operations whose callbacks have never been executed.
+///
+/// Locks of accounts expire on their own after `Contract.lock_expiry`, this API allows to release
+/// them immediately once the stuck operation has been investigated. The lock of a service call
+/// never expires and is released by this API only.
+#[make_integration_version]
+pub trait LockApi {
+    /// Releases the lock of an account stuck in a claim.
+    ///
+    /// This method can only be called by the smart contract itself. It emits an
+    /// `AccountUnlocked` event containing the time the lock was taken at.
+    ///
+    /// # Arguments
+    ///
+    /// * `account_id` - The `AccountId` of the account to unlock.
+    ///
+    /// # Panics
+    ///
+    /// Panics if called by any entity other than the contract itself.
+    ///
+    /// Panics if the contract has no data for the account.
+    fn force_unlock(&mut self, account_id: AccountId);
+
+    /// Releases the lock of a stuck service call, such as a burn.
+    ///
+    /// This method can only be called by the smart contract itself. It emits a
+    /// `ServiceCallReset` event containing the time the service call was started at.
+    ///
+    /// # Panics
+    ///
+    /// Panics if called by any entity other than the contract itself.
+    fn reset_service_call(&mut self);
+}
+
+/// An API for registering claim receivers in the storage of the token contract.
+///
+/// Transfers to accounts which haven't called `storage_deposit` on the token contract fail.
+/// When storage registration is enabled, `claim` checks the receiver's registration with
+/// `storage_balance_of` and pays for it with `storage_deposit` from a pool funded by the owner.
+#[make_integration_version]
+pub trait StorageApi {
+    /// Adds the attached deposit to the pool paying for storage registrations.
+    ///
+    /// This method can only be called by the smart contract itself.
+    ///
+    /// # Panics
+    ///
+    /// Panics if called by any entity other than the contract itself.
+    fn fund_storage_pool(&mut self);
+
+    /// Sets the maximum total deposit paid for storage registrations of a single account.
+    ///
+    /// Each registration attaches the part of the cap the account hasn't been paid yet, the token
+    /// contract refunds everything exceeding its minimum storage balance back to the pool. The kept
+    /// part counts towards the cap, so an account unregistering from the token contract can be
+    /// registered again only until the cap is reached. Registration is skipped once the account
+    /// has been paid the cap, or while the pool holds less than the deposit to attach.
+    ///
+    /// # Arguments
+    ///
+    /// * `cap` - The maximum deposit in yoctoNEAR, or `None` to disable storage registration.
+    ///
+    /// # Panics
+    ///
+    /// Panics if called by any entity other than the contract itself.
+    fn set_storage_deposit_cap(&mut self, cap: Option<U128>);
+
+    /// Retrieves the remaining balance of the storage pool and the per-account deposit cap.
+    fn get_storage_pool(&self) -> StoragePoolView;
 }
 
 /// An API for managing authorization of oracles for sensitive operations in the smart contract.
@@ -115,12 +271,311 @@ pub trait AuthApi {
     fn get_oracles(&self) -> Vec<AccountId>;
 }
 
+/// An API for limiting the amounts oracles are able to record.
+///
+/// This API allows the contract owner to restrict each oracle to a maximum total amount
+/// and a maximum number of entries within a time window.
+///
+/// Windows are fixed rather than rolling: a window starts with the first recording made after
+/// the previous one has elapsed, and its usage is reset at once. An oracle can therefore record
+/// up to twice the quota within `window` seconds spanning the boundary of two windows, so quotas
+/// should be set to half of the amount tolerated within any `window` seconds.
+#[make_integration_version]
+pub trait QuotaApi {
+    /// Sets or removes the recording quota of an oracle.
+    ///
+    /// This method is private and can only be called by the account where the contract
+    /// is deployed. Usage within the current window is preserved when the quota is updated.
+    /// This method emits an `OracleQuotaChanged` event.
+    ///
+    /// # Arguments
+    ///
+    /// * `account_id` - An `AccountId` representing the oracle to be limited.
+    /// * `quota` - An `OracleQuota` to apply, or `None` to remove the limits.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `account_id` is not a registered oracle.
+    fn set_oracle_quota(&mut self, account_id: AccountId, quota: Option<OracleQuota>);
+
+    /// Retrieves the quotas of registered oracles along with their remaining allowance.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Vec<(AccountId, OracleQuotaView)>` containing only oracles with a quota set.
+    fn get_oracle_quotas(&self) -> Vec<(AccountId, OracleQuotaView)>;
+}
+
+/// An API for managing access of individual accounts to the contract's features.
+#[make_integration_version]
+pub trait AccountApi {
+    /// Disables (freezes) an account.
+    ///
+    /// A disabled account keeps accruing tokens, but it is not able to claim them until it's
+    /// enabled again. Accruals of a disabled account are still subject to burn once they
+    /// exceed the `burn_period`.
+    ///
+    /// # Arguments
+    ///
+    /// * `account_id` - An `AccountId` representing the account to be disabled.
+    ///
+    /// # Panics
+    ///
+    /// Panics if called by any entity other than the oracle.
+    ///
+    /// Panics if the account is not registered in the contract.
+    fn disable_account(&mut self, account_id: AccountId);
+
+    /// Enables an account previously disabled with `disable_account`.
+    ///
+    /// # Arguments
+    ///
+    /// * `account_id` - An `AccountId` representing the account to be enabled.
+    ///
+    /// # Panics
+    ///
+    /// Panics if called by any entity other than the oracle.
+    ///
+    /// Panics if the account is not registered in the contract.
+    fn enable_account(&mut self, account_id: AccountId);
+
+    /// Retrieves a page of accounts registered in the contract.
+    ///
+    /// Accounts are listed in the order of their registration. Every account keeps its index
+    /// until it's removed and indices are never reused, so pages don't shift when accounts are
+    /// added or removed. Removed accounts leave gaps, so a page can contain fewer than `limit`
+    /// accounts even if it's not the last one.
+    ///
+    /// # Arguments
+    ///
+    /// * `from_index` - The index of the first account to return.
+    /// * `limit` - The number of indices the page covers.
+    ///
+    /// # Returns
+    ///
+    /// Returns an `AccountsPageView` containing the account IDs on the page and the index
+    /// of the next page, if any.
+    fn get_accounts(&self, from_index: u32, limit: u32) -> AccountsPageView;
+
+    /// Retrieves the number of accounts registered in the contract.
+    fn get_accounts_count(&self) -> u32;
+
+    /// Adds already registered accounts to the index used by `get_accounts`.
+    ///
+    /// Accounts registered before the index was introduced and not passed to `InitApi::migrate`
+    /// are not listed by `get_accounts`. This method allows an oracle to add them using account IDs
+    /// collected, for example, from `record` events. Accounts which are not registered in
+    /// the contract are ignored.
+    ///
+    /// # Arguments
+    ///
+    /// * `account_ids` - A vector of `AccountId`s to be added to the index.
+    ///
+    /// # Returns
+    ///
+    /// Returns the number of accounts added to the index.
+    ///
+    /// # Panics
+    ///
+    /// Panics if called by any entity other than the oracle.
+    fn index_accounts(&mut self, account_ids: Vec<AccountId>) -> u32;
+
+    /// Prunes a page of accounts registered in the contract.
+    ///
+    /// References to burnt and expired accruals are removed from each account. Accounts left
+    /// without accruals which haven't claimed for longer than `burn_period` are deleted, unless
+    /// they are disabled, locked or have their own periods set with `set_account_periods`.
+    /// The activity logs of deleted accounts are deleted as well. Accounts are pruned in pages
+    /// of `get_accounts`, positions of the pages don't change when accounts are deleted.
+    ///
+    /// # Arguments
+    ///
+    /// * `from_index` - The index of the first account to prune.
+    /// * `limit` - The number of indices to prune.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `PruneResultView` with the number of deleted accounts and the `from_index`
+    /// of the next page, if any.
+    ///
+    /// # Panics
+    ///
+    /// Panics if called by any entity other than the oracle.
+    fn prune_accounts(&mut self, from_index: u32, limit: u32) -> PruneResultView;
+
+    /// Sets the account's own claim and burn periods overriding the contract-wide ones.
+    ///
+    /// It allows partner programmes to have a shorter claim cooldown or a longer burn window.
+    /// The new periods apply to the existing accruals of the account as well: if the burn period
+    /// changes, the account's accruals are moved to the buckets of the new burn period, and the ones
+    /// expired under it are logged as expired. This method emits an `AccountPeriodsChanged` event.
+    ///
+    /// # Arguments
+    ///
+    /// * `account_id` - An `AccountId` representing the account.
+    /// * `claim_period` - The account's claim period in seconds, or `None` to use the contract-wide one.
+    /// * `burn_period` - The account's burn period in seconds, or `None` to use the contract-wide one.
+    ///
+    /// # Panics
+    ///
+    /// Panics if called by any entity other than the oracle.
+    ///
+    /// Panics if the account is not registered in the contract.
+    ///
+    /// Panics if the account is locked by a claim in progress.
+    ///
+    /// Panics if any of the periods is longer than ten years.
+    fn set_account_periods(
+        &mut self,
+        account_id: AccountId,
+        claim_period: Option<Duration>,
+        burn_period: Option<Duration>,
+    );
+}
+
+/// An API for retrieving the activity log of accounts.
+#[make_integration_version]
+pub trait HistoryApi {
+    /// Retrieves a page of the activity log of a specified account.
+    ///
+    /// The log contains records, claims and expirations affecting the account's balance.
+    /// Only the most recent entries are kept, limited by the history depth configured
+    /// with `set_history_depth`. The log is disabled until the depth is set. The log is deleted
+    /// along with the account by `prune_accounts` and `clean`, tokens revoked by `clean`
+    /// are reported in its `Clean` event.
+    ///
+    /// # Arguments
+    ///
+    /// * `account_id`: The `AccountId` whose activity log is being queried.
+    /// * `from`: The index of the first entry to return. Entries which were already dropped
+    ///   from the log are skipped.
+    /// * `limit`: The maximum number of entries to return.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Vec<HistoryEntryView>` ordered from the oldest entry to the newest one.
+    fn get_account_history(&self, account_id: AccountId, from: u32, limit: u32) -> Vec<HistoryEntryView>;
+}
+
+/// An API for retrieving aggregated figures of the smart contract.
+#[make_integration_version]
+pub trait StatsApi {
+    /// Retrieves contract-wide statistics.
+    ///
+    /// The statistics are maintained by the operations affecting balances, so this method
+    /// doesn't iterate over the contract state.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `StatsView` containing the amount of tokens currently held by the contract,
+    /// the number and the time range of accrual entries, the number of registered accounts,
+    /// lifetime totals of recorded, claimed, burnt and revoked tokens, and the storage usage
+    /// of the contract in bytes.
+    fn get_stats(&self) -> StatsView;
+}
+
+/// An API for verifying the consistency of the contract state.
+///
+/// It allows auditors and CI to detect accounting bugs by running the checks against any state.
+#[make_integration_version]
+pub trait InvariantApi {
+    /// Checks accounting invariants on a page of the contract state.
+    ///
+    /// The page sums up to `limit` entries of buckets of `accruals` and checks up to `limit`
+    /// accounts, each starting at its own position in `cursor`. For each bucket, the stored total
+    /// must equal the sum of its entries. For each account, every reference to an existing bucket
+    /// must point within it.
+    ///
+    /// The sum of all bucket totals and `pending_burn` must equal the held-balance counter.
+    /// It is checked on the page where the buckets end, using the total carried by the cursor,
+    /// so the result is reliable only if the state hasn't changed between the pages.
+    ///
+    /// # Arguments
+    ///
+    /// * `cursor` - The `next_cursor` of the previous page, `None` for the first page.
+    /// * `limit` - The maximum number of bucket entries and of accounts to check.
+    ///
+    /// # Returns
+    ///
+    /// Returns an `InvariantReport` with the violations found on the page and the cursor
+    /// of the next page, if any.
+    fn check_invariants(&self, cursor: Option<InvariantCursor>, limit: u32) -> InvariantReport;
+}
+
+/// An API for removing accounts from the smart contract.
+///
+/// Cleaning revokes the account's unclaimed tokens and deletes its data. The revoked tokens
+/// are burnt by the next `burn`.
+#[make_integration_version]
+pub trait CleanApi {
+    /// Removes the accounts and revokes their unclaimed tokens.
+    ///
+    /// This method emits a `Clean` event containing the amounts revoked from each account.
+    /// Accounts with a claim in progress are skipped and not included in the event.
+    ///
+    /// # Arguments
+    ///
+    /// * `account_ids` - A vector of `AccountId`s of the accounts to remove.
+    ///
+    /// # Panics
+    ///
+    /// Panics if called by any entity other than the oracle.
+    ///
+    /// Panics if `account_ids` contains more than `MAX_EVENT_BATCH_SIZE` accounts.
+    fn clean(&mut self, account_ids: Vec<AccountId>);
+
+    /// Removes a page of the accounts listed by `AccountApi::get_accounts` and revokes their
+    /// unclaimed tokens.
+    ///
+    /// It allows to clean all accounts of the contract within the gas limit by calling this method
+    /// with the returned index until it returns `None`. Positions of the index don't change when
+    /// accounts are removed, so no account is skipped.
+    ///
+    /// # Arguments
+    ///
+    /// * `from_index` - The position in the account index the page starts at.
+    /// * `limit` - The number of positions in the page.
+    ///
+    /// # Returns
+    ///
+    /// Returns the `from_index` of the next page, or `None` if the end of the index is reached.
+    ///
+    /// # Panics
+    ///
+    /// Panics if called by any entity other than the oracle.
+    ///
+    /// Panics if `limit` is greater than `MAX_EVENT_BATCH_SIZE`.
+    fn clean_paginated(&mut self, from_index: u32, limit: u32) -> Option<u32>;
+
+    /// Returns the amounts `clean` would revoke from the accounts without changing the state.
+    ///
+    /// # Arguments
+    ///
+    /// * `account_ids` - A vector of `AccountId`s of the accounts to check.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Vec<(AccountId, U128)>` containing the amount of unclaimed tokens of each
+    /// account `clean` would remove, in the order of `account_ids`. Accounts with a claim
+    /// in progress are left out, as they are from the `Clean` event.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `account_ids` contains more than `MAX_EVENT_BATCH_SIZE` accounts, like `clean`.
+    fn clean_preview(&self, account_ids: Vec<AccountId>) -> Vec<(AccountId, U128)>;
+}
+
 /// An API for burning unclaimed tokens in the smart contract. This is essential for
 /// managing the lifecycle of tokens and ensuring that unclaimed tokens are appropriately
 /// disposed of after a certain period.
 #[make_integration_version]
 pub trait BurnApi {
-    /// Burns all unclaimed tokens older than `Contract.burn_period`.
+    /// Burns all unclaimed tokens older than the burn period of their accounts.
+    ///
+    /// Accrual entries are stored in buckets shared by the accounts recorded at the same time
+    /// with the same burn period, and a bucket is burnt as a whole once its burn period passes.
+    /// Accounts with their own burn period get buckets of their own, so they neither hold back
+    /// burning of the other accounts' tokens nor have their tokens burnt before their period passes.
     ///
     /// # Returns
     ///
@@ -132,6 +587,8 @@ pub trait BurnApi {
     /// authority to initiate the burn process.
     ///
     /// Panics if another service call is running.
+    ///
+    /// Panics if the call is attached with less gas than `GasConfig::required_for_burn`.
     fn burn(&mut self) -> PromiseOrValue<U128>;
 }
 
@@ -153,6 +610,11 @@ pub trait RecordApi {
     /// # Panics
     ///
     /// Panics if called by any account other than an oracle.
+    ///
+    /// Panics if `amounts` contains more than `MAX_EVENT_BATCH_SIZE` items.
+    ///
+    /// Panics if the batch exceeds the remaining quota of the calling oracle. A `QuotaExceeded`
+    /// event is emitted before the panic and stays in the logs of the failed receipt.
     fn record_batch_for_hold(&mut self, amounts: Vec<(AccountId, U128)>);
 }
 
@@ -174,13 +636,72 @@ pub trait ClaimApi {
     /// `account_id`.
     fn get_claimable_balance_for_account(&self, account_id: AccountId) -> U128;
 
+    /// Retrieves full details of a specified account.
+    ///
+    /// This method returns every live accrual of the account along with its burn deadline,
+    /// the claim state of the account and the total amount of claimable tokens.
+    ///
+    /// # Arguments
+    ///
+    /// * `account_id`: The `AccountId` for which the details are being queried.
+    ///
+    /// # Returns
+    ///
+    /// Returns an `AccountView` for the provided `account_id`, or `None` if the account is not
+    /// registered in the contract.
+    fn get_account(&self, account_id: AccountId) -> Option<AccountView>;
+
+    /// Retrieves the schedule of burning for unclaimed tokens of a specified account.
+    ///
+    /// This method groups the account's live accruals by the moment each of them leaves
+    /// the `burn_period` window and is no longer claimable. It allows to warn users before
+    /// their unclaimed tokens are burnt.
+    ///
+    /// # Arguments
+    ///
+    /// * `account_id`: The `AccountId` for which the schedule is being queried.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Vec<(UnixTimestamp, U128)>` of pairs of a burn moment and the amount of tokens
+    /// to be burnt at that moment, ordered by the burn moment. Returns an empty vector if
+    /// the account is not registered or has no unclaimed tokens.
+    fn get_expiry_schedule(&self, account_id: AccountId) -> Vec<(UnixTimestamp, U128)>;
+
+    /// Retrieves accounts having unclaimed tokens that will be burnt within a time window.
+    ///
+    /// This method iterates over a page of the registered accounts (see `get_accounts`) and
+    /// returns those of them whose tokens leave the `burn_period` window between `from_ts`
+    /// (inclusive) and `to_ts` (exclusive). Since accounts without such tokens are skipped,
+    /// a page can contain fewer than `limit` items; the next page starts at `from_index + limit`.
+    ///
+    /// # Arguments
+    ///
+    /// * `from_ts`: The beginning of the time window.
+    /// * `to_ts`: The end of the time window.
+    /// * `from_index`: The index of the first registered account to check, see `get_accounts`.
+    /// * `limit`: The number of indices to check.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Vec<(AccountId, U128)>` of pairs of an account and the amount of its tokens
+    /// to be burnt within the time window.
+    fn get_accounts_expiring_between(
+        &self,
+        from_ts: UnixTimestamp,
+        to_ts: UnixTimestamp,
+        from_index: u32,
+        limit: u32,
+    ) -> Vec<(AccountId, U128)>;
+
     /// Checks if the claim is available for a specified account.
     ///
     /// This method verifies whether a claim is currently available for the provided `account_id`.
     /// It returns a `ClaimAvailabilityView` enum, which can be either `Available` if the user
     /// can claim immediately, or `Unavailable` with details about the datetime of the last claim
     /// and the claim period duration. If the user has no registered data in the contract, it
-    /// returns `ClaimAvailabilityView.Unregistered`.
+    /// returns `ClaimAvailabilityView.Unregistered`. If the account is disabled by an oracle,
+    /// it returns `ClaimAvailabilityView.Disabled`.
     ///
     /// # Arguments
     ///
@@ -192,6 +713,62 @@ pub trait ClaimApi {
     /// `account_id`.
     fn is_claim_available(&self, account_id: AccountId) -> ClaimAvailabilityView;
 
+    /// Checks if the claim is available for a specified account and what can be claimed.
+    ///
+    /// This method is an extended version of `is_claim_available`. It returns
+    /// a `ClaimAvailabilityViewV2` enum, which can be either `Available` with the amount of tokens
+    /// to be claimed, or `Unavailable` with the moment when the claim becomes available, the number
+    /// of seconds remaining until then and the amount of tokens claimable at the moment. If the
+    /// user is registered but has nothing to claim, it returns `ClaimAvailabilityViewV2.Empty`.
+    /// `Unregistered` and `Disabled` states have the same meaning as in `is_claim_available`.
+    ///
+    /// # Arguments
+    ///
+    /// * `account_id`: The `AccountId` for which claim availability is being checked.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `ClaimAvailabilityViewV2` indicating the claim status for the provided
+    /// `account_id`.
+    fn is_claim_available_v2(&self, account_id: AccountId) -> ClaimAvailabilityViewV2;
+
+    /// Retrieves the amounts of claimable tokens for a batch of accounts.
+    ///
+    /// This method is a batch version of `get_claimable_balance_for_account`.
+    ///
+    /// # Arguments
+    ///
+    /// * `account_ids`: A vector of `AccountId`s for which the claimable balances are being queried.
+    ///   It can contain at most `MAX_BATCH_VIEW_SIZE` items to fit into the gas limit of a view call.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `HashMap<AccountId, U128>` of claimable balances for the provided accounts.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `account_ids` contains more than `MAX_BATCH_VIEW_SIZE` items.
+    fn get_claimable_balances(&self, account_ids: Vec<AccountId>) -> HashMap<AccountId, U128>;
+
+    /// Checks if the claim is available for a batch of accounts.
+    ///
+    /// This method is a batch version of `is_claim_available`.
+    ///
+    /// # Arguments
+    ///
+    /// * `account_ids`: A vector of `AccountId`s for which claim availability is being checked.
+    ///   It can contain at most `MAX_BATCH_VIEW_SIZE` items to fit into the gas limit of a view call.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `HashMap<AccountId, ClaimAvailabilityView>` of claim statuses for the provided
+    /// accounts.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `account_ids` contains more than `MAX_BATCH_VIEW_SIZE` items.
+    fn get_claim_availabilities(&self, account_ids: Vec<AccountId>) -> HashMap<AccountId, ClaimAvailabilityView>;
+
     /// Claims all available tokens for the caller.
     ///
     /// This method allows users to claim all tokens that are available to them at the moment
@@ -205,5 +782,39 @@ pub trait ClaimApi {
     ///
     /// Panics if the claim is unavailable at the moment of calling. Users should ensure that
     /// their claim is available using the `is_claim_available` method prior to calling this.
+    ///
+    /// Panics if the call is attached with less gas than `GasConfig::required_for_claim`, plus
+    /// `GasConfig::required_for_storage_registration` if storage registration is enabled.
     fn claim(&mut self) -> PromiseOrValue<ClaimResultView>;
+
+    /// Retrieves the latest failed claim of an account.
+    ///
+    /// A claim fails when the transfer of claimed tokens fails, e.g. because the account isn't
+    /// registered in the token contract. The claimed tokens are returned to the account then.
+    ///
+    /// # Arguments
+    ///
+    /// * `account_id` - The `AccountId` of the account.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Some(FailedClaimView)` containing the amount, the time and the reason of the
+    /// failure, or `None` if the last claim of the account hasn't failed.
+    fn get_failed_claim(&self, account_id: AccountId) -> Option<FailedClaimView>;
+
+    /// Retries the failed claim of the caller.
+    ///
+    /// Claims all available tokens the same way as `claim`, but doesn't require the claim period
+    /// to pass since the failed attempt.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `PromiseOrValue<ClaimResultView>` indicating the result of the claim operation.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the caller has no failed claim or if the account is disabled.
+    ///
+    /// Panics if the call is attached with less gas than `claim` requires.
+    fn retry_claim(&mut self) -> PromiseOrValue<ClaimResultView>;
 }
@@ -0,0 +1,165 @@
+use std::fmt::{Display, Formatter};
+
+use near_sdk::env;
+
+/// Errors the contract panics with.
+///
+/// Panic messages have the format `[E<code>] <description>` where `<code>` is a zero-padded
+/// three-digit number, e.g. `[E007] Claim is not available at the moment`. Codes are stable and
+/// never reused, so apps can map them to localized text. Descriptions may change between versions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ClaimError {
+    /// `E001`: the method can be called by an oracle only.
+    Unauthorized,
+    /// `E002`: the method can be called by the contract itself only.
+    PrivateMethod,
+    /// `E003`: the account is already an oracle.
+    OracleAlreadyExists,
+    /// `E004`: the account is not an oracle.
+    NoSuchOracle,
+    /// `E005`: a batch method received more accounts than it accepts, see `MAX_BATCH_VIEW_SIZE`
+    /// and `MAX_EVENT_BATCH_SIZE`.
+    BatchTooLarge,
+    /// `E006`: the contract has no data for the account.
+    AccountNotFound,
+    /// `E007`: the claim period hasn't passed yet, or the account is disabled or unregistered.
+    ClaimUnavailable,
+    /// `E008`: a claim of the account is already in progress.
+    AccountLocked,
+    /// `E009`: a burn is already in progress.
+    ServiceCallRunning,
+    /// `E010`: the oracle has exhausted its quota in the current window.
+    QuotaExceeded,
+    /// `E011`: the block timestamp doesn't fit into `UnixTimestamp`.
+    InvalidTimestamp,
+    /// `E012`: the call is attached with less gas than required by `GasConfig`.
+    NotEnoughGas,
+    /// `E013`: the account has no failed claim to retry.
+    NoFailedClaim,
+    /// `E014`: the contract releases more tokens than it accounts as held.
+    HeldBalanceUnderflow,
+    /// `E015`: a configuration value is out of the range the contract accepts.
+    InvalidConfig,
+    /// `E016`: the contract has no state to migrate from.
+    StateNotInitialized,
+}
+
+impl ClaimError {
+    const ALL: [ClaimError; 16] = [
+        ClaimError::Unauthorized,
+        ClaimError::PrivateMethod,
+        ClaimError::OracleAlreadyExists,
+        ClaimError::NoSuchOracle,
+        ClaimError::BatchTooLarge,
+        ClaimError::AccountNotFound,
+        ClaimError::ClaimUnavailable,
+        ClaimError::AccountLocked,
+        ClaimError::ServiceCallRunning,
+        ClaimError::QuotaExceeded,
+        ClaimError::InvalidTimestamp,
+        ClaimError::NotEnoughGas,
+        ClaimError::NoFailedClaim,
+        ClaimError::HeldBalanceUnderflow,
+        ClaimError::InvalidConfig,
+        ClaimError::StateNotInitialized,
+    ];
+
+    pub fn code(&self) -> u16 {
+        match self {
+            ClaimError::Unauthorized => 1,
+            ClaimError::PrivateMethod => 2,
+            ClaimError::OracleAlreadyExists => 3,
+            ClaimError::NoSuchOracle => 4,
+            ClaimError::BatchTooLarge => 5,
+            ClaimError::AccountNotFound => 6,
+            ClaimError::ClaimUnavailable => 7,
+            ClaimError::AccountLocked => 8,
+            ClaimError::ServiceCallRunning => 9,
+            ClaimError::QuotaExceeded => 10,
+            ClaimError::InvalidTimestamp => 11,
+            ClaimError::NotEnoughGas => 12,
+            ClaimError::NoFailedClaim => 13,
+            ClaimError::HeldBalanceUnderflow => 14,
+            ClaimError::InvalidConfig => 15,
+            ClaimError::StateNotInitialized => 16,
+        }
+    }
+
+    pub fn from_code(code: u16) -> Option<Self> {
+        Self::ALL.into_iter().find(|error| error.code() == code)
+    }
+
+    /// Extracts the error from a panic message, e.g. from a failed transaction outcome.
+    pub fn from_panic_message(message: &str) -> Option<Self> {
+        let (_, tail) = message.split_once("[E")?;
+        let (code, _) = tail.split_once(']')?;
+
+        Self::from_code(code.parse().ok()?)
+    }
+
+    pub fn description(&self) -> String {
+        match self {
+            ClaimError::Unauthorized => "Unauthorized access! Only oracle can do this!".to_string(),
+            ClaimError::PrivateMethod => "Method is private".to_string(),
+            ClaimError::OracleAlreadyExists => "Oracle already exists".to_string(),
+            ClaimError::NoSuchOracle => "No such oracle".to_string(),
+            ClaimError::BatchTooLarge => "Batch contains too many accounts".to_string(),
+            ClaimError::AccountNotFound => "Account data is not found".to_string(),
+            ClaimError::ClaimUnavailable => "Claim is not available at the moment".to_string(),
+            ClaimError::AccountLocked => "Another operation is running".to_string(),
+            ClaimError::ServiceCallRunning => "Another service call is running".to_string(),
+            ClaimError::QuotaExceeded => "Oracle quota exceeded".to_string(),
+            ClaimError::InvalidTimestamp => "Failed to convert milliseconds to Unix timestamp".to_string(),
+            ClaimError::NotEnoughGas => "Not enough gas attached".to_string(),
+            ClaimError::NoFailedClaim => "No failed claim to retry".to_string(),
+            ClaimError::HeldBalanceUnderflow => "Released amount exceeds the held balance".to_string(),
+            ClaimError::InvalidConfig => "Configuration value is out of range".to_string(),
+            ClaimError::StateNotInitialized => "Contract state is not initialized".to_string(),
+        }
+    }
+
+    pub fn panic(self) -> ! {
+        env::panic_str(&self.to_string())
+    }
+}
+
+impl Display for ClaimError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "[E{:03}] {}", self.code(), self.description())
+    }
+}
+
+impl std::error::Error for ClaimError {}
+
+#[cfg(test)]
+mod test {
+    use std::collections::HashSet;
+
+    use crate::error::ClaimError;
+
+    #[test]
+    fn error_codes_are_unique() {
+        let codes: HashSet<u16> = ClaimError::ALL.iter().map(ClaimError::code).collect();
+
+        assert_eq!(ClaimError::ALL.len(), codes.len());
+    }
+
+    #[test]
+    fn format_error_message() {
+        assert_eq!(
+            "[E007] Claim is not available at the moment",
+            ClaimError::ClaimUnavailable.to_string()
+        );
+    }
+
+    #[test]
+    fn parse_panic_message() {
+        for error in ClaimError::ALL {
+            let message = format!("Smart contract panicked: {error}");
+            assert_eq!(Some(error), ClaimError::from_panic_message(&message));
+        }
+
+        assert_eq!(None, ClaimError::from_panic_message("Method on_burn is private"));
+        assert_eq!(None, ClaimError::from_panic_message("[E999] Unknown error"));
+    }
+}
@@ -1,11 +1,57 @@
-use near_sdk::{env, json_types::U128, log, serde::Serialize, serde_json, AccountId};
+use std::fmt::{Display, Formatter};
 
-use crate::UnixTimestamp;
+use near_sdk::{
+    env,
+    json_types::U128,
+    log,
+    serde::{Deserialize, Serialize},
+    serde_json, AccountId,
+};
+#[cfg(feature = "schema")]
+use schemars::JsonSchema;
+
+use crate::{
+    failed_claim::ClaimFailureReason, gas_config::GasConfig, oracle_quota::OracleQuota, Duration, UnixTimestamp,
+};
 
 pub const PACKAGE_NAME: &str = "sweat_claim";
-pub const VERSION: &str = env!("CARGO_PKG_VERSION");
+/// Version of the event standard, independent of the crate version.
+///
+/// * `1.0.0` - `burn`, `claim`, `clean` and `record` events.
+/// * `1.1.0` - chunked `claim`, `clean` and `record` events, `CleanData.amounts`, and the account,
+///   oracle, oracle quota, period, config, failed claim and service call events.
+pub const VERSION: &str = "1.1.0";
+
+const EVENT_JSON_PREFIX: &str = "EVENT_JSON:";
+
+/// Maximum size of all logs of a single function call in bytes. NEAR aborts a call exceeding it.
+pub const MAX_TOTAL_LOG_BYTES: usize = 16 * 1024;
+
+/// Maximum size of a single event log in bytes. Events with list payloads exceeding it
+/// are split into several chunked logs. All chunks of an event count towards
+/// `MAX_TOTAL_LOG_BYTES`, so batches emitting them are limited by `MAX_EVENT_BATCH_SIZE`.
+pub const EVENT_LOG_BYTE_BUDGET: usize = 8 * 1024;
+
+/// Upper bound of the serialized size of a list item of `RecordData` or of a pair of `CleanData`
+/// items: a 64-character account ID and a 39-digit amount along with JSON punctuation.
+const MAX_ITEM_BYTES: usize = 111;
+
+/// Upper bound of the serialized size of a log apart from its list items, including
+/// `CHUNK_INFO_RESERVE`.
+const LOG_OVERHEAD_BYTES: usize = 256;
 
-#[derive(Serialize, Debug)]
+/// The minimum number of list items in every chunk but the last one.
+const MIN_ITEMS_PER_LOG: usize = (EVENT_LOG_BYTE_BUDGET - LOG_OVERHEAD_BYTES) / MAX_ITEM_BYTES;
+
+/// The maximum number of entries in a `record_batch_for_hold` or `clean` batch. Chunks of
+/// the batch's event fit into `MAX_TOTAL_LOG_BYTES` in total whatever the account IDs and amounts are.
+pub const MAX_EVENT_BATCH_SIZE: usize =
+    MIN_ITEMS_PER_LOG * (MAX_TOTAL_LOG_BYTES / (MIN_ITEMS_PER_LOG * MAX_ITEM_BYTES + LOG_OVERHEAD_BYTES));
+
+const CHUNK_INFO_RESERVE: usize = 128;
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 #[serde(
     crate = "near_sdk::serde",
     tag = "event",
@@ -13,37 +59,163 @@ pub const VERSION: &str = env!("CARGO_PKG_VERSION");
     rename_all = "snake_case"
 )]
 pub enum EventKind {
+    AccountDisabled(AccountData),
+    AccountEnabled(AccountData),
+    AccountPeriodsChanged(AccountPeriodsData),
+    AccountUnlocked(UnlockData),
     Burn(BurnData),
+    BurnPeriodChanged(PeriodData),
     Claim(ClaimData),
+    ClaimFailed(ClaimFailedData),
+    ClaimPeriodChanged(PeriodData),
     Clean(CleanData),
+    GasConfigChanged(GasConfig),
+    HistoryDepthChanged(HistoryDepthData),
+    LockExpiryChanged(PeriodData),
+    OracleAdded(OracleData),
+    OracleQuotaChanged(OracleQuotaData),
+    OracleRemoved(OracleData),
+    QuotaExceeded(QuotaExceededData),
     Record(RecordData),
+    ServiceCallReset(ServiceCallResetData),
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+#[serde(crate = "near_sdk::serde")]
+pub struct AccountData {
+    pub account_id: AccountId,
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+#[serde(crate = "near_sdk::serde")]
+pub struct AccountPeriodsData {
+    pub account_id: AccountId,
+    pub claim_period: Option<Duration>,
+    pub burn_period: Option<Duration>,
 }
 
-#[derive(Serialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 #[serde(crate = "near_sdk::serde")]
 pub struct BurnData {
     pub burnt_amount: U128,
 }
 
-#[derive(Serialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 #[serde(crate = "near_sdk::serde")]
 pub struct ClaimData {
     pub account_id: AccountId,
     pub details: Vec<(UnixTimestamp, U128)>,
     pub total_claimed: U128,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub chunk: Option<EventChunk>,
 }
 
-#[derive(Serialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+#[serde(crate = "near_sdk::serde")]
+pub struct ClaimFailedData {
+    pub account_id: AccountId,
+    pub amount: U128,
+    pub reason: ClaimFailureReason,
+    pub attempts: u32,
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 #[serde(crate = "near_sdk::serde")]
 pub struct CleanData {
     pub account_ids: Vec<AccountId>,
+    /// Amounts removed from the accounts in the order of `account_ids`. The removed tokens
+    /// are burnt by the next `burn`.
+    #[serde(default)]
+    pub amounts: Vec<U128>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub chunk: Option<EventChunk>,
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+#[serde(crate = "near_sdk::serde")]
+pub struct HistoryDepthData {
+    pub depth: u32,
+}
+
+/// Position of a log within an event that was split into several logs.
+///
+/// All chunks of an event share the same `batch_id`. Chunks are numbered from 0 to `count - 1`;
+/// concatenating their lists in this order restores the original event.
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+#[serde(crate = "near_sdk::serde")]
+pub struct EventChunk {
+    pub batch_id: String,
+    pub index: u32,
+    pub count: u32,
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+#[serde(crate = "near_sdk::serde")]
+pub struct OracleData {
+    pub account_id: AccountId,
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+#[serde(crate = "near_sdk::serde")]
+pub struct OracleQuotaData {
+    pub account_id: AccountId,
+    /// The new quota of the oracle, `None` if the oracle is no longer limited.
+    pub quota: Option<OracleQuota>,
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+#[serde(crate = "near_sdk::serde")]
+pub struct PeriodData {
+    pub period: Duration,
 }
 
-#[derive(Serialize, Debug)]
+/// Emitted right before a batch over the oracle's quota is rejected. The call fails afterwards,
+/// so its state changes are reverted, while the log stays in the receipt outcome.
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+#[serde(crate = "near_sdk::serde")]
+pub struct QuotaExceededData {
+    pub account_id: AccountId,
+    pub requested_amount: U128,
+    pub requested_entries: u32,
+    pub remaining_amount: U128,
+    pub remaining_entries: u32,
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+#[serde(crate = "near_sdk::serde")]
+pub struct ServiceCallResetData {
+    pub started_at: Option<UnixTimestamp>,
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+#[serde(crate = "near_sdk::serde")]
+pub struct UnlockData {
+    pub account_id: AccountId,
+    pub locked_at: Option<UnixTimestamp>,
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 #[serde(crate = "near_sdk::serde")]
 pub struct RecordData {
     pub timestamp: UnixTimestamp,
     pub amounts: Vec<(AccountId, U128)>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub chunk: Option<EventChunk>,
 }
 
 impl RecordData {
@@ -51,83 +223,459 @@ impl RecordData {
         Self {
             timestamp,
             amounts: vec![],
+            chunk: None,
+        }
+    }
+}
+
+impl EventKind {
+    /// Returns the serialized size of the list payload which can be split across chunks.
+    fn splittable_size(&self) -> usize {
+        match self {
+            EventKind::Claim(data) => json_size(&data.details),
+            EventKind::Clean(data) => json_size(&data.account_ids) + json_size(&data.amounts),
+            EventKind::Record(data) => json_size(&data.amounts),
+            _ => 0,
+        }
+    }
+
+    /// Splits the list payload into events whose lists fit `items_budget` bytes each.
+    /// Events without a list payload are returned as is.
+    fn split(self, items_budget: usize) -> Vec<EventKind> {
+        match self {
+            EventKind::Claim(data) => split_items(data.details, items_budget)
+                .into_iter()
+                .map(|details| {
+                    EventKind::Claim(ClaimData {
+                        account_id: data.account_id.clone(),
+                        details,
+                        total_claimed: data.total_claimed,
+                        chunk: None,
+                    })
+                })
+                .collect(),
+            EventKind::Clean(data) => {
+                let items: Vec<_> = data.account_ids.into_iter().zip(data.amounts).collect();
+
+                split_items(items, items_budget)
+                    .into_iter()
+                    .map(|items| {
+                        let (account_ids, amounts) = items.into_iter().unzip();
+                        EventKind::Clean(CleanData {
+                            account_ids,
+                            amounts,
+                            chunk: None,
+                        })
+                    })
+                    .collect()
+            }
+            EventKind::Record(data) => split_items(data.amounts, items_budget)
+                .into_iter()
+                .map(|amounts| {
+                    EventKind::Record(RecordData {
+                        timestamp: data.timestamp,
+                        amounts,
+                        chunk: None,
+                    })
+                })
+                .collect(),
+            event_kind => vec![event_kind],
+        }
+    }
+
+    fn set_chunk(&mut self, chunk: EventChunk) {
+        match self {
+            EventKind::Claim(data) => data.chunk = Some(chunk),
+            EventKind::Clean(data) => data.chunk = Some(chunk),
+            EventKind::Record(data) => data.chunk = Some(chunk),
+            _ => {}
         }
     }
 }
 
-#[derive(Serialize, Debug)]
+fn json_size<T: Serialize>(items: &[T]) -> usize {
+    items.iter().map(item_json_size).sum()
+}
+
+/// Serialized size of a list item including the separating comma.
+fn item_json_size<T: Serialize>(item: &T) -> usize {
+    serde_json::to_string(item).map_or(0, |json| json.len()) + 1
+}
+
+/// Groups items so that serialized size of every group fits the budget.
+/// A group always contains at least one item.
+fn split_items<T: Serialize>(items: Vec<T>, budget: usize) -> Vec<Vec<T>> {
+    let mut groups = vec![];
+    let mut group = vec![];
+    let mut group_size = 0;
+
+    for item in items {
+        let item_size = item_json_size(&item);
+
+        if !group.is_empty() && group_size + item_size > budget {
+            groups.push(std::mem::take(&mut group));
+            group_size = 0;
+        }
+
+        group_size += item_size;
+        group.push(item);
+    }
+
+    if !group.is_empty() || groups.is_empty() {
+        groups.push(group);
+    }
+
+    groups
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 #[serde(crate = "near_sdk::serde", rename_all = "snake_case")]
-struct SweatClaimEvent {
-    standard: &'static str,
-    version: &'static str,
+pub struct SweatClaimEvent {
+    pub standard: String,
+    pub version: String,
     #[serde(flatten)]
-    event_kind: EventKind,
+    pub event_kind: EventKind,
 }
 
 impl From<EventKind> for SweatClaimEvent {
     fn from(event_kind: EventKind) -> Self {
         Self {
-            standard: PACKAGE_NAME,
-            version: VERSION,
+            standard: PACKAGE_NAME.to_string(),
+            version: VERSION.to_string(),
             event_kind,
         }
     }
 }
 
+#[derive(Debug, PartialEq)]
+pub enum EventParseError {
+    NotAnEvent,
+    InvalidJson(String),
+    UnknownStandard(String),
+    UnsupportedVersion(String),
+}
+
+impl Display for EventParseError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            EventParseError::NotAnEvent => write!(f, "Log doesn't start with {EVENT_JSON_PREFIX}"),
+            EventParseError::InvalidJson(err) => write!(f, "Failed to deserialize SweatClaimEvent: {err}"),
+            EventParseError::UnknownStandard(standard) => write!(f, "Unknown event standard: {standard}"),
+            EventParseError::UnsupportedVersion(version) => write!(f, "Unsupported event version: {version}"),
+        }
+    }
+}
+
+impl std::error::Error for EventParseError {}
+
+/// Parses a log emitted by the contract into a `SweatClaimEvent`.
+///
+/// Events of any version sharing the major version with `VERSION` are accepted. Fields added
+/// by a minor version get their defaults when an older event is parsed: `chunk` is `None` and
+/// `CleanData.amounts` is empty. Event kinds unknown to this crate fail with `InvalidJson`.
+pub fn parse_event_log(log: &str) -> Result<SweatClaimEvent, EventParseError> {
+    let json = log.strip_prefix(EVENT_JSON_PREFIX).ok_or(EventParseError::NotAnEvent)?;
+
+    let event: SweatClaimEvent =
+        serde_json::from_str(json).map_err(|err| EventParseError::InvalidJson(err.to_string()))?;
+
+    if event.standard != PACKAGE_NAME {
+        return Err(EventParseError::UnknownStandard(event.standard));
+    }
+
+    if major_version(&event.version) != major_version(VERSION) {
+        return Err(EventParseError::UnsupportedVersion(event.version));
+    }
+
+    Ok(event)
+}
+
+fn major_version(version: &str) -> &str {
+    version.split('.').next().unwrap_or_default()
+}
+
+/// Logs the event in compact JSON. `Record`, `Clean` and `Claim` events exceeding
+/// `EVENT_LOG_BYTE_BUDGET` are split into several logs, see `EventChunk`.
 pub fn emit(event: EventKind) {
-    log!(SweatClaimEvent::from(event).to_json_event_string());
+    for event in SweatClaimEvent::from(event).into_chunks(EVENT_LOG_BYTE_BUDGET) {
+        log!(event.to_json_event_string());
+    }
 }
 
 impl SweatClaimEvent {
     fn to_json_string(&self) -> String {
-        serde_json::to_string_pretty(self)
+        serde_json::to_string(self)
             .unwrap_or_else(|err| env::panic_str(&format!("Failed to serialize SweatClaimEvent: {err}")))
     }
 
+    /// Splits the event into chunks with serialized size within `budget` where possible.
+    /// An event that fits the budget is returned as a single event without chunk info.
+    fn into_chunks(self, budget: usize) -> Vec<SweatClaimEvent> {
+        let json = self.to_json_event_string();
+        if json.len() <= budget {
+            return vec![self];
+        }
+
+        let overhead = json.len() - self.event_kind.splittable_size() + CHUNK_INFO_RESERVE;
+        let chunks = self.event_kind.split(budget.saturating_sub(overhead));
+        if chunks.len() == 1 {
+            return chunks.into_iter().map(SweatClaimEvent::from).collect();
+        }
+
+        let batch_id: String = env::sha256(json.as_bytes())[..8]
+            .iter()
+            .map(|byte| format!("{byte:02x}"))
+            .collect();
+        let count = chunks.len() as u32;
+
+        chunks
+            .into_iter()
+            .enumerate()
+            .map(|(index, mut event_kind)| {
+                event_kind.set_chunk(EventChunk {
+                    batch_id: batch_id.clone(),
+                    index: index as u32,
+                    count,
+                });
+
+                SweatClaimEvent {
+                    standard: self.standard.clone(),
+                    version: self.version.clone(),
+                    event_kind,
+                }
+            })
+            .collect()
+    }
+
     fn to_json_event_string(&self) -> String {
-        format!("EVENT_JSON:{}", self.to_json_string())
+        format!("{EVENT_JSON_PREFIX}{}", self.to_json_string())
     }
 }
 
 #[cfg(test)]
 mod test {
-    use near_sdk::json_types::U128;
+    use near_sdk::{json_types::U128, AccountId};
 
-    use crate::event::{BurnData, EventKind, SweatClaimEvent};
+    use crate::event::{
+        parse_event_log, BurnData, CleanData, EventKind, EventParseError, OracleData, PeriodData, RecordData,
+        SweatClaimEvent, EVENT_LOG_BYTE_BUDGET, MAX_EVENT_BATCH_SIZE, MAX_TOTAL_LOG_BYTES,
+    };
 
     #[test]
     fn event_to_string() {
         assert_eq!(
-            strip(
-                SweatClaimEvent::from(EventKind::Burn(BurnData {
-                    burnt_amount: U128(100_000_000),
-                }))
-                .to_json_event_string()
-                .as_str()
-            ),
-            strip(
-                r#"EVENT_JSON:{
-                "standard": "sweat_claim",
-                "version": "1.0.0",
-                "event": "burn",
-                "data": {
-                  "burnt_amount": "100000000"
-                }}"#
-            )
+            SweatClaimEvent::from(EventKind::Burn(BurnData {
+                burnt_amount: U128(100_000_000),
+            }))
+            .to_json_event_string(),
+            r#"EVENT_JSON:{"standard":"sweat_claim","version":"1.1.0","event":"burn","data":{"burnt_amount":"100000000"}}"#
         )
     }
 
-    fn strip(s: &str) -> String {
-        let without_newlines: String = s.chars().filter(|&c| c != '\n').collect();
-        let mut previous_char = ' ';
-        let result: String = without_newlines
-            .chars()
-            .filter(|&c| {
-                let keep = !(c == ' ' && previous_char == ' ');
-                previous_char = c;
-                keep
+    #[test]
+    fn oracle_event_to_string() {
+        assert_eq!(
+            SweatClaimEvent::from(EventKind::OracleAdded(OracleData {
+                account_id: AccountId::new_unchecked("oracle.near".to_string()),
+            }))
+            .to_json_event_string(),
+            r#"EVENT_JSON:{"standard":"sweat_claim","version":"1.1.0","event":"oracle_added","data":{"account_id":"oracle.near"}}"#
+        )
+    }
+
+    #[test]
+    fn period_event_to_string() {
+        assert_eq!(
+            SweatClaimEvent::from(EventKind::ClaimPeriodChanged(PeriodData { period: 3_600 })).to_json_event_string(),
+            r#"EVENT_JSON:{"standard":"sweat_claim","version":"1.1.0","event":"claim_period_changed","data":{"period":3600}}"#
+        )
+    }
+
+    #[test]
+    fn small_event_is_not_chunked() {
+        let event = SweatClaimEvent::from(EventKind::Record(record_data(3)));
+        let json = event.to_json_event_string();
+
+        let chunks = event.into_chunks(EVENT_LOG_BYTE_BUDGET);
+
+        assert_eq!(1, chunks.len());
+        assert_eq!(json, chunks[0].to_json_event_string());
+        assert!(!json.contains("chunk"));
+    }
+
+    #[test]
+    fn large_record_event_is_chunked() {
+        const BUDGET: usize = 512;
+
+        let data = record_data(50);
+        let expected_amounts = data.amounts.clone();
+
+        let chunks = SweatClaimEvent::from(EventKind::Record(data)).into_chunks(BUDGET);
+        assert!(chunks.len() > 1);
+
+        let mut batch_ids = vec![];
+        let mut amounts = vec![];
+
+        for (index, chunk) in chunks.into_iter().enumerate() {
+            let json = chunk.to_json_event_string();
+            assert!(json.len() <= BUDGET);
+
+            let EventKind::Record(data) = parse_event_log(&json).unwrap().event_kind else {
+                panic!("Expected record event");
+            };
+            let chunk_info = data.chunk.unwrap();
+
+            assert_eq!(index as u32, chunk_info.index);
+            assert_eq!(1_705_066_501, data.timestamp);
+
+            batch_ids.push(chunk_info.batch_id);
+            amounts.extend(data.amounts);
+        }
+
+        batch_ids.dedup();
+        assert_eq!(1, batch_ids.len());
+        assert_eq!(expected_amounts, amounts);
+    }
+
+    #[test]
+    fn large_clean_event_is_chunked() {
+        let account_ids: Vec<AccountId> = (0..50)
+            .map(|i| AccountId::new_unchecked(format!("account_{i}.near")))
+            .collect();
+
+        let amounts: Vec<U128> = (0..50).map(U128).collect();
+
+        let chunks = SweatClaimEvent::from(EventKind::Clean(CleanData {
+            account_ids: account_ids.clone(),
+            amounts: amounts.clone(),
+            chunk: None,
+        }))
+        .into_chunks(512);
+
+        let count = chunks.len() as u32;
+        assert!(count > 1);
+
+        let (restored_account_ids, restored_amounts): (Vec<AccountId>, Vec<U128>) = chunks
+            .into_iter()
+            .flat_map(|chunk| {
+                let EventKind::Clean(data) = chunk.event_kind else {
+                    panic!("Expected clean event");
+                };
+                assert_eq!(count, data.chunk.unwrap().count);
+                data.account_ids.into_iter().zip(data.amounts)
             })
+            .unzip();
+
+        assert_eq!(account_ids, restored_account_ids);
+        assert_eq!(amounts, restored_amounts);
+    }
+
+    #[test]
+    fn max_event_batch_fits_total_log_limit() {
+        let account_ids: Vec<AccountId> = (0..MAX_EVENT_BATCH_SIZE)
+            .map(|i| AccountId::new_unchecked(format!("{i:0>64}")))
             .collect();
-        result
+        let amounts = vec![U128(u128::MAX); MAX_EVENT_BATCH_SIZE];
+
+        let events = [
+            EventKind::Record(RecordData {
+                timestamp: u32::MAX,
+                amounts: account_ids.iter().cloned().zip(amounts.iter().copied()).collect(),
+                chunk: None,
+            }),
+            EventKind::Clean(CleanData {
+                account_ids,
+                amounts,
+                chunk: None,
+            }),
+        ];
+
+        for event in events {
+            let chunks = SweatClaimEvent::from(event).into_chunks(EVENT_LOG_BYTE_BUDGET);
+            let total_size: usize = chunks.iter().map(|chunk| chunk.to_json_event_string().len()).sum();
+
+            assert!(chunks.len() > 1);
+            assert!(total_size <= MAX_TOTAL_LOG_BYTES, "{total_size} bytes logged");
+        }
+    }
+
+    #[test]
+    fn parse_emitted_event() {
+        let event = SweatClaimEvent::from(EventKind::Record(record_data(1)));
+
+        let parsed = parse_event_log(&event.to_json_event_string()).unwrap();
+        assert_eq!(event, parsed);
+    }
+
+    #[test]
+    fn parse_event_log_of_compatible_version() {
+        let parsed = parse_event_log(
+            r#"EVENT_JSON:{"standard":"sweat_claim","version":"1.0.0","event":"burn","data":{"burnt_amount":"100"}}"#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            EventKind::Burn(BurnData {
+                burnt_amount: U128(100)
+            }),
+            parsed.event_kind
+        );
+    }
+
+    #[test]
+    fn parse_event_log_of_initial_version() {
+        let parsed = parse_event_log(
+            r#"EVENT_JSON:{"standard":"sweat_claim","version":"1.0.0","event":"clean","data":{"account_ids":["alice.near"]}}"#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            EventKind::Clean(CleanData {
+                account_ids: vec![AccountId::new_unchecked("alice.near".to_string())],
+                amounts: vec![],
+                chunk: None,
+            }),
+            parsed.event_kind
+        );
+    }
+
+    #[test]
+    fn parse_invalid_event_logs() {
+        assert_eq!(Err(EventParseError::NotAnEvent), parse_event_log("Oracle was added"));
+
+        assert!(matches!(
+            parse_event_log(r#"EVENT_JSON:{"standard":"sweat_claim","version":"1.0.0","event":"unknown"}"#),
+            Err(EventParseError::InvalidJson(_))
+        ));
+
+        assert_eq!(
+            Err(EventParseError::UnknownStandard("nep141".to_string())),
+            parse_event_log(
+                r#"EVENT_JSON:{"standard":"nep141","version":"1.0.0","event":"burn","data":{"burnt_amount":"100"}}"#
+            )
+        );
+
+        assert_eq!(
+            Err(EventParseError::UnsupportedVersion("2.0.0".to_string())),
+            parse_event_log(
+                r#"EVENT_JSON:{"standard":"sweat_claim","version":"2.0.0","event":"burn","data":{"burnt_amount":"100"}}"#
+            )
+        );
+    }
+
+    fn record_data(accounts_count: u32) -> RecordData {
+        RecordData {
+            timestamp: 1_705_066_501,
+            amounts: (0..accounts_count)
+                .map(|i| {
+                    (
+                        AccountId::new_unchecked(format!("account_{i}.near")),
+                        U128(100 * i as u128),
+                    )
+                })
+                .collect(),
+            chunk: None,
+        }
     }
 }
@@ -0,0 +1,39 @@
+use near_sdk::{
+    borsh,
+    borsh::{BorshDeserialize, BorshSerialize},
+    serde::{Deserialize, Serialize},
+};
+#[cfg(feature = "schema")]
+use schemars::JsonSchema;
+
+use crate::{TokensAmount, UnixTimestamp};
+
+/// The reason why a claim has failed.
+#[derive(BorshDeserialize, BorshSerialize, Serialize, Deserialize, Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+#[serde(crate = "near_sdk::serde", rename_all = "snake_case")]
+pub enum ClaimFailureReason {
+    /// `ft_transfer` on the token contract has failed, e.g. because the account isn't
+    /// registered in the token contract.
+    TransferFailed,
+    /// `storage_deposit` on the token contract has failed while registering the account.
+    StorageRegistrationFailed,
+}
+
+/// A claim whose transfer has failed. The claimed tokens are returned to the account,
+/// and the claim can be retried with `retry_claim` regardless of the claim period.
+#[derive(BorshDeserialize, BorshSerialize)]
+pub struct FailedClaim {
+    /// The amount of tokens of the failed claim returned to the account, which `retry_claim` transfers again.
+    /// Tokens of entries burnt while the transfer was in flight are not included.
+    pub amount: TokensAmount,
+
+    /// The timestamp of the latest failure.
+    pub failed_at: UnixTimestamp,
+
+    /// The reason of the latest failure.
+    pub reason: ClaimFailureReason,
+
+    /// The number of failed attempts since the last successful claim.
+    pub attempts: u32,
+}
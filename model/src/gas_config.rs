@@ -0,0 +1,101 @@
+use near_sdk::{
+    borsh,
+    borsh::{BorshDeserialize, BorshSerialize},
+    serde::{Deserialize, Serialize},
+    Gas,
+};
+#[cfg(feature = "schema")]
+use schemars::JsonSchema;
+
+const DEFAULT_CALL_GAS: Gas = Gas(10 * Gas::ONE_TERA.0);
+const MIN_CALL_GAS: Gas = Gas(5 * Gas::ONE_TERA.0);
+const MAX_PREPAID_GAS: Gas = Gas(300 * Gas::ONE_TERA.0);
+
+/// Gas attached to cross-contract calls made by the contract.
+///
+/// Callbacks receive at least the configured amount. On top of it they get all the gas that is left
+/// unused after the calling method finishes, so a callback's gas grows with the gas attached to the call.
+#[derive(BorshDeserialize, BorshSerialize, Serialize, Deserialize, Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+#[serde(crate = "near_sdk::serde")]
+pub struct GasConfig {
+    /// Gas attached to `ft_transfer` on the token contract during a claim.
+    pub transfer: Gas,
+    /// The minimum gas for the `on_transfer` callback.
+    pub transfer_callback: Gas,
+    /// Gas attached to `burn` on the token contract.
+    pub burn: Gas,
+    /// The minimum gas for the `on_burn` callback.
+    pub burn_callback: Gas,
+    /// Gas reserved for the execution of `claim` and `burn` themselves.
+    pub execution: Gas,
+    /// Gas attached to `storage_balance_of` and `storage_deposit` on the token contract
+    /// when a claim registers its receiver.
+    pub storage_registration: Gas,
+    /// The minimum gas for each of the callbacks of the storage registration calls.
+    pub storage_registration_callback: Gas,
+}
+
+impl Default for GasConfig {
+    fn default() -> Self {
+        Self {
+            transfer: DEFAULT_CALL_GAS,
+            transfer_callback: DEFAULT_CALL_GAS,
+            burn: DEFAULT_CALL_GAS,
+            burn_callback: DEFAULT_CALL_GAS,
+            execution: DEFAULT_CALL_GAS,
+            storage_registration: DEFAULT_CALL_GAS,
+            storage_registration_callback: DEFAULT_CALL_GAS,
+        }
+    }
+}
+
+impl GasConfig {
+    /// The minimum gas a `claim` call must be attached with.
+    pub fn required_for_claim(&self) -> Gas {
+        self.execution + self.transfer + self.transfer_callback
+    }
+
+    /// The additional gas a `claim` call must be attached with when it may register its receiver
+    /// in the token contract. Both `storage_balance_of` and `storage_deposit` are accounted.
+    pub fn required_for_storage_registration(&self) -> Gas {
+        (self.storage_registration + self.storage_registration_callback) * 2
+    }
+
+    /// The minimum gas a `burn` call must be attached with.
+    pub fn required_for_burn(&self) -> Gas {
+        self.execution + self.burn + self.burn_callback
+    }
+
+    /// Checks that each call gets at least 5 TGas and that both a claim registering its receiver
+    /// and a burn fit into the 300 TGas a transaction can be attached with.
+    pub fn is_valid(&self) -> bool {
+        let total = |gas: &[Gas]| {
+            gas.iter()
+                .try_fold(0u64, |total, gas| total.checked_add(gas.0))
+                .is_some_and(|total| total <= MAX_PREPAID_GAS.0)
+        };
+
+        let calls = [
+            self.transfer,
+            self.transfer_callback,
+            self.burn,
+            self.burn_callback,
+            self.execution,
+            self.storage_registration,
+            self.storage_registration_callback,
+        ];
+
+        calls.iter().all(|gas| *gas >= MIN_CALL_GAS)
+            && total(&[
+                self.execution,
+                self.transfer,
+                self.transfer_callback,
+                self.storage_registration,
+                self.storage_registration,
+                self.storage_registration_callback,
+                self.storage_registration_callback,
+            ])
+            && total(&[self.execution, self.burn, self.burn_callback])
+    }
+}
@@ -1,10 +1,27 @@
+pub mod account_history;
 pub mod account_record;
 pub mod api;
+pub mod error;
 pub mod event;
+pub mod failed_claim;
+pub mod gas_config;
+pub mod oracle_quota;
+#[cfg(feature = "schema")]
+pub mod schema;
+pub mod stats;
 
 use near_sdk::{
-    json_types::U128,
+    json_types::{U128, U64},
     serde::{Deserialize, Serialize},
+    AccountId,
+};
+#[cfg(feature = "schema")]
+use schemars::JsonSchema;
+
+use crate::{
+    account_history::HistoryEntryKind,
+    failed_claim::{ClaimFailureReason, FailedClaim},
+    oracle_quota::{OracleQuota, OracleQuotaRecord},
 };
 
 pub type UnixTimestamp = u32;
@@ -12,15 +29,243 @@ pub type AccrualIndex = u32;
 pub type TokensAmount = u128;
 pub type Duration = u32; // Period in seconds
 
+/// The maximum number of accounts accepted by batch view methods such as `get_claimable_balances`.
+pub const MAX_BATCH_VIEW_SIZE: usize = 100;
+
 #[derive(Serialize, Deserialize, Debug, PartialEq)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 #[serde(crate = "near_sdk::serde", tag = "type", content = "data", rename_all = "snake_case")]
 pub enum ClaimAvailabilityView {
     Available,
     Unavailable((UnixTimestamp, Duration)),
     Unregistered,
+    Disabled,
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+#[serde(crate = "near_sdk::serde", tag = "type", content = "data", rename_all = "snake_case")]
+pub enum ClaimAvailabilityViewV2 {
+    Available {
+        claimable_amount: U128,
+    },
+    Unavailable {
+        available_at: UnixTimestamp,
+        seconds_remaining: Duration,
+        claimable_amount: U128,
+    },
+    Empty,
+    Unregistered,
+    Disabled,
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+#[serde(crate = "near_sdk::serde")]
+pub struct AccountsPageView {
+    pub account_ids: Vec<AccountId>,
+    /// The `from_index` of the next page, `None` if this is the last one.
+    pub next_index: Option<u32>,
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+#[serde(crate = "near_sdk::serde")]
+pub struct PruneResultView {
+    /// The number of accounts deleted from the page.
+    pub deleted: u32,
+    /// The `from_index` of the next page, `None` if this is the last one.
+    pub next_index: Option<u32>,
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+#[serde(crate = "near_sdk::serde")]
+pub struct AccountView {
+    /// Live accruals as `(timestamp, amount, burn_deadline)` tuples.
+    pub accruals: Vec<(UnixTimestamp, U128, UnixTimestamp)>,
+    pub claim_period_refreshed_at: UnixTimestamp,
+    /// The earliest moment when the account is able to claim.
+    pub next_claim_at: UnixTimestamp,
+    pub is_locked: bool,
+    pub is_enabled: bool,
+    pub claimable_amount: U128,
+    /// The claim period applied to the account, either its own or the contract-wide one.
+    pub claim_period: Duration,
+    /// The burn period applied to the account, either its own or the contract-wide one.
+    pub burn_period: Duration,
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+#[serde(crate = "near_sdk::serde")]
+pub struct OracleQuotaView {
+    pub quota: OracleQuota,
+    pub remaining_amount: U128,
+    pub remaining_entries: u32,
+    pub window_ends_at: UnixTimestamp,
+}
+
+impl From<OracleQuotaRecord> for OracleQuotaView {
+    fn from(record: OracleQuotaRecord) -> Self {
+        Self {
+            remaining_amount: U128(record.remaining_amount()),
+            remaining_entries: record.remaining_entries(),
+            window_ends_at: record.window_ends_at(),
+            quota: record.quota,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+#[serde(crate = "near_sdk::serde")]
+pub struct HistoryEntryView {
+    pub index: u32,
+    pub timestamp: UnixTimestamp,
+    pub kind: HistoryEntryKind,
+    pub amount: U128,
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+#[serde(crate = "near_sdk::serde")]
+pub struct StatsView {
+    pub total_held: U128,
+    /// Tokens which are not assigned to any account and are burnt by the next `burn`.
+    pub pending_burn: U128,
+    pub buckets_count: u32,
+    pub oldest_bucket_at: Option<UnixTimestamp>,
+    pub newest_bucket_at: Option<UnixTimestamp>,
+    pub accounts_count: u32,
+    /// Equals `total_claimed + total_burned + total_held`.
+    pub total_recorded: U128,
+    pub total_claimed: U128,
+    /// Includes revoked tokens once they are burnt.
+    pub total_burned: U128,
+    /// Tokens revoked from cleaned accounts. Revoked tokens are burnt by `burn` like expired ones,
+    /// so once burnt they are counted in `total_burned` as well, i.e. this is not a separate term
+    /// of the balance of `total_recorded`.
+    pub total_revoked: U128,
+    pub storage_usage: U64,
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+#[serde(crate = "near_sdk::serde")]
+pub struct FailedClaimView {
+    pub amount: U128,
+    pub failed_at: UnixTimestamp,
+    pub reason: ClaimFailureReason,
+    pub attempts: u32,
+}
+
+impl From<&FailedClaim> for FailedClaimView {
+    fn from(failed_claim: &FailedClaim) -> Self {
+        Self {
+            amount: U128(failed_claim.amount),
+            failed_at: failed_claim.failed_at,
+            reason: failed_claim.reason,
+            attempts: failed_claim.attempts,
+        }
+    }
+}
+
+/// A bucket in `accruals` whose stored total differs from the sum of its entries.
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+#[serde(crate = "near_sdk::serde")]
+pub struct BucketTotalMismatch {
+    /// The burn period override of the bucket's accounts, `None` for the contract-wide burn period.
+    pub burn_period: Option<Duration>,
+    pub timestamp: UnixTimestamp,
+    pub stored_total: U128,
+    pub actual_total: U128,
+}
+
+/// A reference in `AccountRecord.accruals` pointing past the end of its bucket.
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+#[serde(crate = "near_sdk::serde")]
+pub struct DanglingAccrual {
+    pub account_id: AccountId,
+    pub timestamp: UnixTimestamp,
+    pub index: AccrualIndex,
+    pub bucket_len: u32,
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+#[serde(crate = "near_sdk::serde")]
+pub struct InvariantReport {
+    pub buckets_checked: u32,
+    pub accounts_checked: u32,
+    pub bucket_total_mismatches: Vec<BucketTotalMismatch>,
+    pub dangling_accruals: Vec<DanglingAccrual>,
+    /// The sum of stored totals of the buckets checked on this page and on the previous pages.
+    pub buckets_total: U128,
+    /// The held-balance counter, which must equal the sum of all bucket totals and `pending_burn`.
+    pub total_held: U128,
+    pub pending_burn: U128,
+    /// Whether the sum of all bucket totals and `pending_burn` equals `total_held`.
+    /// `None` until the pages have covered all buckets.
+    pub is_held_balance_consistent: Option<bool>,
+    /// The cursor of the next page, `None` if this is the last one.
+    pub next_cursor: Option<InvariantCursor>,
+}
+
+/// The position `check_invariants` continues from. Buckets and accounts are paged independently,
+/// and a bucket larger than a page is summed over several pages.
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+#[serde(crate = "near_sdk::serde")]
+pub struct InvariantCursor {
+    /// The position of the bucket to continue from in the index of buckets.
+    pub bucket_index: u32,
+    /// The index of the first entry of that bucket which isn't summed yet.
+    pub entry_index: u32,
+    /// The sum of the entries of that bucket before `entry_index`.
+    pub bucket_partial_total: U128,
+    /// The sum of stored totals of the buckets checked on the previous pages.
+    pub buckets_total: U128,
+    /// The position of the first account to check in the account index.
+    pub account_index: u32,
+}
+
+impl Default for InvariantCursor {
+    /// Returns the cursor of the first page.
+    fn default() -> Self {
+        Self {
+            bucket_index: 0,
+            entry_index: 0,
+            bucket_partial_total: U128(0),
+            buckets_total: U128(0),
+            account_index: 0,
+        }
+    }
+}
+
+impl InvariantReport {
+    /// Returns `true` if no violations were found on this page.
+    pub fn is_ok(&self) -> bool {
+        self.bucket_total_mismatches.is_empty()
+            && self.dangling_accruals.is_empty()
+            && self.is_held_balance_consistent != Some(false)
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+#[serde(crate = "near_sdk::serde")]
+pub struct StoragePoolView {
+    /// yoctoNEAR available for storage deposits of claim receivers.
+    pub balance: U128,
+    /// The maximum total deposit paid for a single account. `None` if storage registration is disabled.
+    pub deposit_cap: Option<U128>,
 }
 
 #[derive(Serialize, Deserialize, Debug, PartialEq)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 #[serde(crate = "near_sdk::serde")]
 pub struct ClaimResultView {
     pub total: U128,
@@ -0,0 +1,88 @@
+use near_sdk::{
+    borsh,
+    borsh::{BorshDeserialize, BorshSerialize},
+    json_types::U128,
+    serde::{Deserialize, Serialize},
+};
+#[cfg(feature = "schema")]
+use schemars::JsonSchema;
+
+use crate::{Duration, TokensAmount, UnixTimestamp};
+
+/// Limits applied to the amounts an oracle can record within a single window.
+///
+/// `OracleQuota` is configured by the contract owner for each oracle and bounds the damage
+/// a compromised oracle key can do through `record_batch_for_hold`.
+#[derive(BorshDeserialize, BorshSerialize, Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+#[serde(crate = "near_sdk::serde")]
+pub struct OracleQuota {
+    /// The maximum total amount of tokens the oracle can record within a window.
+    pub max_amount: U128,
+
+    /// The maximum number of entries the oracle can record within a window.
+    pub max_entries: u32,
+
+    /// The length of the window in seconds.
+    pub window: Duration,
+}
+
+/// Represents the quota of an oracle along with its usage within the current window.
+///
+/// A window starts with the first recording made after the previous window has elapsed.
+/// Windows are fixed, so usage is reset at once when a new window starts.
+#[derive(BorshDeserialize, BorshSerialize, Clone)]
+pub struct OracleQuotaRecord {
+    /// The limits configured for the oracle.
+    pub quota: OracleQuota,
+
+    /// The timestamp when the current window has started.
+    pub window_started_at: UnixTimestamp,
+
+    /// The total amount of tokens recorded by the oracle within the current window.
+    pub used_amount: TokensAmount,
+
+    /// The number of entries recorded by the oracle within the current window.
+    pub used_entries: u32,
+}
+
+impl OracleQuotaRecord {
+    pub fn new(quota: OracleQuota, now: UnixTimestamp) -> Self {
+        Self {
+            quota,
+            window_started_at: now,
+            used_amount: 0,
+            used_entries: 0,
+        }
+    }
+
+    /// Starts a new window if the current one has elapsed.
+    pub fn refresh(&mut self, now: UnixTimestamp) {
+        if now - self.window_started_at >= self.quota.window {
+            self.window_started_at = now;
+            self.used_amount = 0;
+            self.used_entries = 0;
+        }
+    }
+
+    pub fn remaining_amount(&self) -> TokensAmount {
+        self.quota.max_amount.0.saturating_sub(self.used_amount)
+    }
+
+    pub fn remaining_entries(&self) -> u32 {
+        self.quota.max_entries.saturating_sub(self.used_entries)
+    }
+
+    pub fn window_ends_at(&self) -> UnixTimestamp {
+        self.window_started_at.saturating_add(self.quota.window)
+    }
+
+    pub fn allows(&self, amount: TokensAmount, entries: u32) -> bool {
+        amount <= self.remaining_amount() && entries <= self.remaining_entries()
+    }
+
+    pub fn consume(&mut self, amount: TokensAmount, entries: u32) {
+        self.used_amount += amount;
+        self.used_entries += entries;
+    }
+}
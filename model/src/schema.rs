@@ -0,0 +1,249 @@
+//! JSON Schema of the contract interface.
+//!
+//! Describes arguments and results of every method declared in [`crate::api`] and the events
+//! emitted by the contract. The schema is committed to `model/schema.json`, run `make schema`
+//! to regenerate it after changing the interface.
+
+use near_sdk::{
+    json_types::U128,
+    serde::Serialize,
+    serde_json::{self, json, Value},
+    AccountId, PromiseOrValue,
+};
+use schemars::{gen::SchemaSettings, schema::Schema};
+
+use crate::{
+    api::{
+        AccountApi, AuthApi, BurnApi, ClaimApi, CleanApi, ConfigApi, HistoryApi, InitApi, InvariantApi, LockApi,
+        QuotaApi, RecordApi, StatsApi, StorageApi,
+    },
+    event::SweatClaimEvent,
+    gas_config::GasConfig,
+    oracle_quota::OracleQuota,
+    AccountView, AccountsPageView, ClaimAvailabilityView, ClaimAvailabilityViewV2, ClaimResultView, Duration,
+    FailedClaimView, HistoryEntryView, InvariantCursor, InvariantReport, OracleQuotaView, PruneResultView, StatsView,
+    StoragePoolView, UnixTimestamp,
+};
+
+#[derive(Serialize)]
+#[serde(crate = "near_sdk::serde")]
+struct MethodSchema {
+    api: &'static str,
+    name: &'static str,
+    /// `init`, `view` or `call`.
+    kind: &'static str,
+    params: Vec<ParamSchema>,
+    result: Schema,
+}
+
+#[derive(Serialize)]
+#[serde(crate = "near_sdk::serde")]
+struct ParamSchema {
+    name: &'static str,
+    schema: Schema,
+}
+
+/// Describes methods of an API trait. The listed methods are implemented for a probe type at compile time,
+/// so a method missing from the list, an extra method or a changed signature fails the build.
+/// Parameter names are checked against the trait declaration by `params_match_api_declaration`.
+macro_rules! api_methods {
+    ($generator:ident, $api:ident { $($kind:ident $name:ident($($arg:ident: $arg_ty:ty),*) -> $result:ty;)* }) => {{
+        #[allow(dead_code)]
+        struct Probe;
+
+        #[allow(unused_variables)]
+        impl $api for Probe {
+            $(api_methods!(@probe $kind, $name, ($($arg: $arg_ty),*), $result);)*
+        }
+
+        vec![$(MethodSchema {
+            api: stringify!($api),
+            name: stringify!($name),
+            kind: stringify!($kind),
+            params: vec![$(ParamSchema {
+                name: stringify!($arg),
+                schema: $generator.subschema_for::<$arg_ty>(),
+            }),*],
+            result: $generator.subschema_for::<$result>(),
+        }),*]
+    }};
+    (@probe init, $name:ident, ($($arg:ident: $arg_ty:ty),*), $result:ty) => {
+        fn $name($($arg: $arg_ty),*) -> Self {
+            unimplemented!()
+        }
+    };
+    (@probe view, $name:ident, ($($arg:ident: $arg_ty:ty),*), $result:ty) => {
+        fn $name(&self, $($arg: $arg_ty),*) -> $result {
+            unimplemented!()
+        }
+    };
+    (@probe call, $name:ident, ($($arg:ident: $arg_ty:ty),*), $result:ty) => {
+        fn $name(&mut self, $($arg: $arg_ty),*) -> $result {
+            unimplemented!()
+        }
+    };
+}
+
+/// Builds the JSON Schema of all contract methods and events.
+pub fn contract_schema() -> Value {
+    let mut generator = SchemaSettings::draft07().into_generator();
+
+    let methods: Vec<MethodSchema> = [
+        api_methods!(generator, InitApi {
+            init init(token_account_id: AccountId) -> ();
+            init migrate(account_ids: Vec<AccountId>) -> ();
+        }),
+        api_methods!(generator, ConfigApi {
+            call set_claim_period(period: Duration) -> ();
+            call set_burn_period(period: Duration) -> ();
+            call set_history_depth(depth: u32) -> ();
+            call set_lock_expiry(expiry: Duration) -> ();
+            call set_gas_config(gas_config: GasConfig) -> ();
+            view get_gas_config() -> GasConfig;
+        }),
+        api_methods!(generator, LockApi {
+            call force_unlock(account_id: AccountId) -> ();
+            call reset_service_call() -> ();
+        }),
+        api_methods!(generator, StorageApi {
+            call fund_storage_pool() -> ();
+            call set_storage_deposit_cap(cap: Option<U128>) -> ();
+            view get_storage_pool() -> StoragePoolView;
+        }),
+        api_methods!(generator, AuthApi {
+            call add_oracle(account_id: AccountId) -> ();
+            call remove_oracle(account_id: AccountId) -> ();
+            view get_oracles() -> Vec<AccountId>;
+        }),
+        api_methods!(generator, QuotaApi {
+            call set_oracle_quota(account_id: AccountId, quota: Option<OracleQuota>) -> ();
+            view get_oracle_quotas() -> Vec<(AccountId, OracleQuotaView)>;
+        }),
+        api_methods!(generator, AccountApi {
+            call disable_account(account_id: AccountId) -> ();
+            call enable_account(account_id: AccountId) -> ();
+            view get_accounts(from_index: u32, limit: u32) -> AccountsPageView;
+            view get_accounts_count() -> u32;
+            call index_accounts(account_ids: Vec<AccountId>) -> u32;
+            call prune_accounts(from_index: u32, limit: u32) -> PruneResultView;
+            call set_account_periods(
+                account_id: AccountId,
+                claim_period: Option<Duration>,
+                burn_period: Option<Duration>
+            ) -> ();
+        }),
+        api_methods!(generator, HistoryApi {
+            view get_account_history(account_id: AccountId, from: u32, limit: u32) -> Vec<HistoryEntryView>;
+        }),
+        api_methods!(generator, StatsApi {
+            view get_stats() -> StatsView;
+        }),
+        api_methods!(generator, InvariantApi {
+            view check_invariants(cursor: Option<InvariantCursor>, limit: u32) -> InvariantReport;
+        }),
+        api_methods!(generator, CleanApi {
+            call clean(account_ids: Vec<AccountId>) -> ();
+            call clean_paginated(from_index: u32, limit: u32) -> Option<u32>;
+            view clean_preview(account_ids: Vec<AccountId>) -> Vec<(AccountId, U128)>;
+        }),
+        api_methods!(generator, BurnApi {
+            call burn() -> PromiseOrValue<U128>;
+        }),
+        api_methods!(generator, RecordApi {
+            call record_batch_for_hold(amounts: Vec<(AccountId, U128)>) -> ();
+        }),
+        api_methods!(generator, ClaimApi {
+            view get_claimable_balance_for_account(account_id: AccountId) -> U128;
+            view get_account(account_id: AccountId) -> Option<AccountView>;
+            view get_expiry_schedule(account_id: AccountId) -> Vec<(UnixTimestamp, U128)>;
+            view get_accounts_expiring_between(
+                from_ts: UnixTimestamp,
+                to_ts: UnixTimestamp,
+                from_index: u32,
+                limit: u32
+            ) -> Vec<(AccountId, U128)>;
+            view is_claim_available(account_id: AccountId) -> ClaimAvailabilityView;
+            view is_claim_available_v2(account_id: AccountId) -> ClaimAvailabilityViewV2;
+            view get_claimable_balances(account_ids: Vec<AccountId>) -> std::collections::HashMap<AccountId, U128>;
+            view get_claim_availabilities(
+                account_ids: Vec<AccountId>
+            ) -> std::collections::HashMap<AccountId, ClaimAvailabilityView>;
+            call claim() -> PromiseOrValue<ClaimResultView>;
+            view get_failed_claim(account_id: AccountId) -> Option<FailedClaimView>;
+            call retry_claim() -> PromiseOrValue<ClaimResultView>;
+        }),
+    ]
+    .into_iter()
+    .flatten()
+    .collect();
+
+    let events = generator.subschema_for::<SweatClaimEvent>();
+
+    json!({
+        "$schema": generator.settings().meta_schema,
+        "methods": methods,
+        "events": events,
+        "definitions": generator.definitions(),
+    })
+}
+
+/// Returns the schema in the format it is committed to the repository.
+pub fn contract_schema_string() -> String {
+    let mut schema = serde_json::to_string_pretty(&contract_schema()).expect("Failed to serialize contract schema");
+    schema.push('\n');
+    schema
+}
+
+#[cfg(test)]
+mod test {
+    use std::{env, fs, path::PathBuf};
+
+    use crate::schema::{contract_schema, contract_schema_string};
+
+    #[test]
+    fn schema_is_up_to_date() {
+        let path = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("schema.json");
+        let schema = contract_schema_string();
+
+        if env::var("UPDATE_SCHEMA").is_ok() {
+            fs::write(&path, schema).unwrap();
+            return;
+        }
+
+        let committed = fs::read_to_string(&path).unwrap_or_default();
+        assert!(
+            committed == schema,
+            "model/schema.json doesn't match the code. Run `make schema` to regenerate it."
+        );
+    }
+
+    #[test]
+    fn params_match_api_declaration() {
+        let source = include_str!("api.rs");
+        let schema = contract_schema();
+
+        for method in schema["methods"].as_array().unwrap() {
+            let name = method["name"].as_str().unwrap();
+            let declaration = source
+                .split_once(&format!("fn {name}("))
+                .unwrap_or_else(|| panic!("`{name}` is not declared in the API"))
+                .1;
+            let declared: Vec<&str> = declaration[..declaration.find(')').unwrap()]
+                .split(',')
+                .filter_map(|param| param.split_once(':'))
+                .map(|(param, _)| param.trim())
+                .collect();
+            let listed: Vec<&str> = method["params"]
+                .as_array()
+                .unwrap()
+                .iter()
+                .map(|param| param["name"].as_str().unwrap())
+                .collect();
+
+            assert_eq!(
+                declared, listed,
+                "Parameters of `{name}` differ from the API declaration"
+            );
+        }
+    }
+}
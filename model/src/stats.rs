@@ -0,0 +1,44 @@
+use near_sdk::{
+    borsh,
+    borsh::{BorshDeserialize, BorshSerialize},
+};
+
+use crate::{error::ClaimError, TokensAmount, UnixTimestamp};
+
+/// Contract-wide counters maintained along with the operations affecting the balances.
+///
+/// `Stats` allows to retrieve aggregated figures without iterating over the contract state.
+#[derive(BorshDeserialize, BorshSerialize, Default)]
+pub struct Stats {
+    /// The total amount of tokens currently held in `Contract.accruals` and `Contract.pending_burn`.
+    pub total_held: TokensAmount,
+
+    /// The timestamp of the oldest entry in `Contract.accruals`.
+    pub oldest_bucket_at: Option<UnixTimestamp>,
+
+    /// The timestamp of the newest entry in `Contract.accruals`.
+    pub newest_bucket_at: Option<UnixTimestamp>,
+
+    /// The total amount of tokens ever recorded.
+    pub total_recorded: TokensAmount,
+
+    /// The total amount of tokens ever claimed.
+    pub total_claimed: TokensAmount,
+
+    /// The total amount of tokens ever burnt, including revoked ones.
+    pub total_burned: TokensAmount,
+
+    /// The total amount of tokens ever revoked from cleaned accounts.
+    /// Revoked tokens are counted in `total_burned` as well once they are burnt.
+    pub total_revoked: TokensAmount,
+}
+
+impl Stats {
+    /// Deducts tokens which have left the contract from `total_held`.
+    pub fn release_held(&mut self, amount: TokensAmount) {
+        self.total_held = self
+            .total_held
+            .checked_sub(amount)
+            .unwrap_or_else(|| ClaimError::HeldBalanceUnderflow.panic());
+    }
+}